@@ -0,0 +1,78 @@
+#![feature(test)]
+
+extern crate test;
+
+use fastrand::Rng;
+use fastrand_contrib::RngExt;
+use test::Bencher;
+
+const SEED: u64 = 42;
+const LEN: usize = 1024;
+const LOW: f64 = -1.0;
+const HIGH: f64 = 1.0;
+const MU: f64 = 10.0;
+const SIGMA: f64 = 3.0;
+
+#[bench]
+fn naive_f64_range(b: &mut Bencher) {
+    let mut rng = Rng::with_seed(SEED);
+    let mut buf = [0.0; LEN];
+
+    b.iter(|| {
+        let low = core::hint::black_box(LOW);
+        let high = core::hint::black_box(HIGH);
+
+        for x in buf.iter_mut() {
+            *x = rng.f64_range(low..high);
+        }
+
+        core::hint::black_box(&buf);
+    });
+}
+
+#[bench]
+fn fill_f64_range(b: &mut Bencher) {
+    let mut rng = Rng::with_seed(SEED);
+    let mut buf = [0.0; LEN];
+
+    b.iter(|| {
+        let low = core::hint::black_box(LOW);
+        let high = core::hint::black_box(HIGH);
+
+        rng.fill_f64_range(&mut buf, low..high);
+
+        core::hint::black_box(&buf);
+    });
+}
+
+#[bench]
+fn naive_f64_normal(b: &mut Bencher) {
+    let mut rng = Rng::with_seed(SEED);
+    let mut buf = [0.0; LEN];
+
+    b.iter(|| {
+        let mu = core::hint::black_box(MU);
+        let sigma = core::hint::black_box(SIGMA);
+
+        for x in buf.iter_mut() {
+            *x = rng.f64_normal(mu, sigma);
+        }
+
+        core::hint::black_box(&buf);
+    });
+}
+
+#[bench]
+fn fill_f64_normal(b: &mut Bencher) {
+    let mut rng = Rng::with_seed(SEED);
+    let mut buf = [0.0; LEN];
+
+    b.iter(|| {
+        let mu = core::hint::black_box(MU);
+        let sigma = core::hint::black_box(SIGMA);
+
+        rng.fill_f64_normal(&mut buf, mu, sigma);
+
+        core::hint::black_box(&buf);
+    });
+}
@@ -117,3 +117,17 @@ fn f64_normal_approx(b: &mut Bencher) {
         core::hint::black_box(output);
     });
 }
+
+#[bench]
+fn f64_normal_ziggurat(b: &mut Bencher) {
+    let mut rng = Rng::with_seed(SEED);
+
+    b.iter(|| {
+        let mu = core::hint::black_box(MU);
+        let sigma = core::hint::black_box(SIGMA);
+
+        let output = rng.f64_normal_ziggurat(mu, sigma);
+
+        core::hint::black_box(output);
+    });
+}
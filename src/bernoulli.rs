@@ -0,0 +1,83 @@
+use crate::BaseRng;
+
+pub(super) fn bool_with_prob(rng: &mut impl BaseRng, p: f64) -> bool {
+    debug_assert!(!p.is_nan(), "probability must not be NaN");
+
+    if p <= 0.0 {
+        false
+    } else if p >= 1.0 {
+        true
+    } else {
+        rng.f64() < p
+    }
+}
+
+pub(super) fn chance(rng: &mut impl BaseRng, numerator: u64, denominator: u64) -> bool {
+    debug_assert!(denominator > 0, "denominator must be positive");
+    debug_assert!(
+        numerator <= denominator,
+        "numerator must not be greater than denominator"
+    );
+
+    // fastrand's integer ranges are unbiased, which makes the probability
+    // exact, unlike comparing against the rounded `numerator / denominator`.
+    numerator > 0 && rng.u64_range(0..denominator) < numerator
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn bool_with_prob_follows_probability() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 1_000_000;
+        let hits = (0..total).filter(|_| bool_with_prob(&mut rng, 0.3)).count();
+        let freq = hits as f64 / total as f64;
+        assert!(
+            (freq - 0.3).abs() < 0.01,
+            "true should be sampled ~30%, but is {}%",
+            freq * 100.0
+        );
+
+        for _ in 0..1000 {
+            assert!(!bool_with_prob(&mut rng, 0.0));
+            assert!(!bool_with_prob(&mut rng, -1.0));
+            assert!(bool_with_prob(&mut rng, 1.0));
+            assert!(bool_with_prob(&mut rng, 2.0));
+        }
+    }
+
+    #[test]
+    fn chance_follows_ratio() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 5_000_000;
+        let hits = (0..total)
+            .filter(|_| chance(&mut rng, 1, 1_000_000))
+            .count();
+        // The expected count is 5, with a standard deviation of ~2.2.
+        assert!(
+            (1..=20).contains(&hits),
+            "1 in 1000000 fired {} times",
+            hits
+        );
+
+        let total = 300_000;
+        let hits = (0..total).filter(|_| chance(&mut rng, 1, 3)).count();
+        let freq = hits as f64 / total as f64;
+        assert!(
+            (freq - 1.0 / 3.0).abs() < 0.005,
+            "1 in 3 should fire ~33.3%, but is {}%",
+            freq * 100.0
+        );
+
+        for _ in 0..1000 {
+            assert!(!chance(&mut rng, 0, 7));
+            assert!(chance(&mut rng, 7, 7));
+        }
+    }
+}
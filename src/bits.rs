@@ -0,0 +1,42 @@
+use crate::BaseRng;
+
+pub(super) fn u64_differing(rng: &mut impl BaseRng, previous: u64, min_bit_diff: u32) -> u64 {
+    assert!(min_bit_diff <= 64, "u64 has only 64 bits to differ in");
+
+    // Choose `min_bit_diff` distinct bit positions with a partial Fisher-Yates
+    // shuffle.
+    let mut positions = [0u8; 64];
+    for (i, position) in positions.iter_mut().enumerate() {
+        *position = i as u8;
+    }
+    let mut mask = 0u64;
+    for k in 0..min_bit_diff as usize {
+        positions.swap(k, rng.usize(k..64));
+        mask |= 1 << positions[k];
+    }
+
+    // The chosen bits are flipped, the rest is random.
+    (!previous & mask) | (rng.u64() & !mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn u64_differing_has_min_hamming_distance() {
+        let mut rng = Rng::with_seed(42);
+
+        for min_bit_diff in 0..=64 {
+            for _ in 0..100 {
+                let previous = rng.u64(..);
+                let value = u64_differing(&mut rng, previous, min_bit_diff);
+                assert!((previous ^ value).count_ones() >= min_bit_diff);
+            }
+        }
+
+        assert_eq!(u64_differing(&mut rng, 0x1234, 64), !0x1234);
+    }
+}
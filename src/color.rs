@@ -0,0 +1,159 @@
+use alloc::vec::Vec;
+
+use crate::BaseRng;
+
+/// The color-theory relationship between the hues generated by
+/// [`color_palette`](crate::RngExt::color_palette).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PaletteScheme {
+    /// Alternates between a base hue and the hue opposite to it.
+    Complementary,
+    /// Cycles through three hues evenly spaced around the color wheel.
+    Triadic,
+    /// Uses hues within 30° of a base hue.
+    Analogous,
+    /// Uses a single hue with varying saturation and brightness.
+    Monochromatic,
+}
+
+pub(super) fn color_palette(
+    rng: &mut impl BaseRng,
+    count: usize,
+    scheme: PaletteScheme,
+) -> Vec<[u8; 3]> {
+    let base_hue = rng.f64() * 360.0;
+
+    (0..count)
+        .map(|i| {
+            let offset = match scheme {
+                PaletteScheme::Complementary => [0.0, 180.0][i % 2],
+                PaletteScheme::Triadic => [0.0, 120.0, 240.0][i % 3],
+                PaletteScheme::Analogous => [0.0, 30.0, -30.0][i % 3],
+                PaletteScheme::Monochromatic => 0.0,
+            };
+
+            // Colors that share a hue are told apart by their saturation and
+            // brightness. Both are kept away from zero so the hue stays
+            // visible.
+            let (saturation, value) = match scheme {
+                PaletteScheme::Monochromatic => (0.2 + rng.f64() * 0.8, 0.3 + rng.f64() * 0.7),
+                _ => (0.4 + rng.f64() * 0.6, 0.5 + rng.f64() * 0.5),
+            };
+
+            hsv_to_rgb(base_hue + offset, saturation, value)
+        })
+        .collect()
+}
+
+/// Convert a color given by its hue in degrees, saturation and value in
+/// `[0, 1]` to RGB.
+fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> [u8; 3] {
+    let hue = hue % 360.0;
+    let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+
+    // The hue selects one of six sectors of the RGB cube. Within a sector the
+    // largest component is `value`, the smallest is `value - chroma`, and the
+    // middle one moves linearly between them.
+    let sector = hue / 60.0;
+    let chroma = value * saturation;
+    let middle = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, middle, 0.0),
+        1 => (middle, chroma, 0.0),
+        2 => (0.0, chroma, middle),
+        3 => (0.0, middle, chroma),
+        4 => (middle, 0.0, chroma),
+        _ => (chroma, 0.0, middle),
+    };
+
+    let min = value - chroma;
+    let to_u8 = |x: f64| ((x + min) * 255.0 + 0.5) as u8;
+    [to_u8(r), to_u8(g), to_u8(b)]
+}
+
+/// Convert an RGB color to its hue in degrees, saturation and value.
+#[cfg(test)]
+fn rgb_to_hsv([r, g, b]: [u8; 3]) -> (f64, f64, f64) {
+    let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let chroma = max - min;
+
+    let sector = if chroma == 0.0 {
+        0.0
+    } else if max == r {
+        (g - b) / chroma
+    } else if max == g {
+        (b - r) / chroma + 2.0
+    } else {
+        (r - g) / chroma + 4.0
+    };
+    let hue = sector * 60.0;
+    let hue = if hue < 0.0 { hue + 360.0 } else { hue };
+    let saturation = if max == 0.0 { 0.0 } else { chroma / max };
+
+    (hue, saturation, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    /// The signed difference between two hues, in `(-180, 180]`.
+    fn hue_diff(a: f64, b: f64) -> f64 {
+        let diff = (b - a) % 360.0;
+        if diff > 180.0 {
+            diff - 360.0
+        } else if diff <= -180.0 {
+            diff + 360.0
+        } else {
+            diff
+        }
+    }
+
+    #[test]
+    fn hsv_round_trips() {
+        let mut rng = Rng::with_seed(42);
+
+        for _ in 0..1000 {
+            let hue = rng.f64() * 360.0;
+            let (h, s, v) = rgb_to_hsv(hsv_to_rgb(hue, 1.0, 1.0));
+            assert!(hue_diff(hue, h).abs() < 1.0, "{} != {}", hue, h);
+            assert_eq!((s, v), (1.0, 1.0));
+        }
+    }
+
+    #[test]
+    fn color_palette_follows_scheme() {
+        let mut rng = Rng::with_seed(42);
+
+        let schemes = [
+            (PaletteScheme::Complementary, &[0.0, 180.0][..]),
+            (PaletteScheme::Triadic, &[0.0, 120.0, -120.0][..]),
+            (PaletteScheme::Analogous, &[0.0, 30.0, -30.0][..]),
+            (PaletteScheme::Monochromatic, &[0.0][..]),
+        ];
+        for &(scheme, offsets) in schemes.iter() {
+            for _ in 0..100 {
+                let palette = color_palette(&mut rng, 7, scheme);
+                assert_eq!(palette.len(), 7);
+
+                let base = rgb_to_hsv(palette[0]).0;
+                for (i, &color) in palette.iter().enumerate() {
+                    let diff = hue_diff(base, rgb_to_hsv(color).0);
+                    let expected = offsets[i % offsets.len()];
+                    assert!(
+                        hue_diff(expected, diff).abs() < 3.0,
+                        "{:?}: color {} is {}° from the base hue, expected {}°",
+                        scheme,
+                        i,
+                        diff,
+                        expected
+                    );
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use core::fmt;
+
+use crate::BaseRng;
+
+/// The error returned when dice notation can't be parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DiceError {
+    /// The notation has no `d` separating the number of dice from the number
+    /// of sides.
+    MissingDie,
+    /// The number of dice is not a valid number.
+    InvalidCount,
+    /// The number of sides is not a valid positive number.
+    InvalidSides,
+    /// The modifier after `+` or `-` is not a valid number.
+    InvalidModifier,
+    /// The total doesn't fit in `i64`.
+    Overflow,
+    /// More than [`MAX_DICE`](DiceError::MAX_DICE) dice are rolled.
+    TooManyDice,
+}
+
+impl DiceError {
+    /// The maximum number of dice that can be rolled at once.
+    ///
+    /// Every die is rolled separately, so this bounds the time spent on
+    /// untrusted notation.
+    pub const MAX_DICE: u64 = 10_000;
+}
+
+impl fmt::Display for DiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DiceError::MissingDie => f.write_str("dice notation is missing the `d`"),
+            DiceError::InvalidCount => f.write_str("invalid number of dice"),
+            DiceError::InvalidSides => f.write_str("invalid number of sides"),
+            DiceError::InvalidModifier => f.write_str("invalid modifier"),
+            DiceError::Overflow => f.write_str("dice total overflows"),
+            DiceError::TooManyDice => f.write_str("too many dice"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DiceError {}
+
+pub(super) fn roll_dice(rng: &mut impl BaseRng, notation: &str) -> Result<i64, DiceError> {
+    let notation = notation.trim();
+    let d = notation
+        .find(|c| c == 'd' || c == 'D')
+        .ok_or(DiceError::MissingDie)?;
+
+    // The number of dice may be omitted, e.g. `d20`.
+    let count = match &notation[..d] {
+        "" => 1,
+        count => parse_number(count).ok_or(DiceError::InvalidCount)?,
+    };
+
+    let rest = &notation[d + 1..];
+    let (sides, modifier) = match rest.find(|c| c == '+' || c == '-') {
+        Some(sign) => {
+            let modifier = parse_number(&rest[sign + 1..]).ok_or(DiceError::InvalidModifier)?;
+            let modifier = if modifier > i64::MAX as u64 {
+                return Err(DiceError::Overflow);
+            } else if rest[sign..].starts_with('-') {
+                -(modifier as i64)
+            } else {
+                modifier as i64
+            };
+            (&rest[..sign], modifier)
+        }
+        None => (rest, 0),
+    };
+    let sides = match parse_number(sides) {
+        Some(sides) if sides > 0 => sides,
+        _ => return Err(DiceError::InvalidSides),
+    };
+
+    if count > DiceError::MAX_DICE {
+        return Err(DiceError::TooManyDice);
+    }
+    // Check the largest possible sum of the rolls upfront. This also ensures
+    // that every roll fits in `i64`.
+    match count.checked_mul(sides) {
+        Some(max) if max <= i64::MAX as u64 => {}
+        _ => return Err(DiceError::Overflow),
+    }
+
+    let mut total = modifier;
+    for _ in 0..count {
+        let roll = roll_die(rng, sides) as i64;
+        total = total.checked_add(roll).ok_or(DiceError::Overflow)?;
+    }
+
+    Ok(total)
+}
+
+/// Parse a non-empty sequence of decimal digits.
+fn parse_number(s: &str) -> Option<u64> {
+    // `u64::from_str` would also accept a leading `+`.
+    if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    s.parse().ok()
+}
+
+/// Roll a die with the given number of sides without modulo bias.
+fn roll_die(rng: &mut impl BaseRng, sides: u64) -> u64 {
+    // Draws below `2^64 mod sides` are rejected.
+    let threshold = sides.wrapping_neg() % sides;
+    loop {
+        let x = rng.u64();
+        if x >= threshold {
+            return x % sides + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn roll_dice_stays_in_range() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut seen = [false; 13];
+        for _ in 0..1000 {
+            let total = roll_dice(&mut rng, "2d6").unwrap();
+            assert!((2..=12).contains(&total));
+            seen[total as usize] = true;
+        }
+        assert!(seen[2..].iter().all(|&seen| seen));
+
+        let mut sum = 0;
+        for _ in 0..10000 {
+            let total = roll_dice(&mut rng, "d20").unwrap();
+            assert!((1..=20).contains(&total));
+            sum += total;
+        }
+        let mean = sum as f64 / 10000.0;
+        assert!((mean - 10.5).abs() < 0.2, "mean is {}", mean);
+
+        for _ in 0..1000 {
+            let total = roll_dice(&mut rng, "1d4-5").unwrap();
+            assert!((-4..=-1).contains(&total));
+            let total = roll_dice(&mut rng, " 3D6+2 ").unwrap();
+            assert!((5..=20).contains(&total));
+        }
+        assert_eq!(roll_dice(&mut rng, "0d6+3"), Ok(3));
+        assert_eq!(roll_dice(&mut rng, "5d1"), Ok(5));
+    }
+
+    #[test]
+    fn roll_dice_rejects_malformed_notation() {
+        let mut rng = Rng::with_seed(42);
+
+        assert_eq!(roll_dice(&mut rng, "abc"), Err(DiceError::MissingDie));
+        assert_eq!(roll_dice(&mut rng, ""), Err(DiceError::MissingDie));
+        assert_eq!(roll_dice(&mut rng, "xd6"), Err(DiceError::InvalidCount));
+        assert_eq!(roll_dice(&mut rng, "+2d6"), Err(DiceError::InvalidCount));
+        assert_eq!(roll_dice(&mut rng, "2d"), Err(DiceError::InvalidSides));
+        assert_eq!(roll_dice(&mut rng, "2d0"), Err(DiceError::InvalidSides));
+        assert_eq!(roll_dice(&mut rng, "2d6+"), Err(DiceError::InvalidModifier));
+        assert_eq!(
+            roll_dice(&mut rng, "2d6+-1"),
+            Err(DiceError::InvalidModifier)
+        );
+        assert_eq!(
+            roll_dice(&mut rng, "2d6+9223372036854775807"),
+            Err(DiceError::Overflow)
+        );
+    }
+
+    #[test]
+    fn roll_dice_rejects_huge_counts() {
+        let mut rng = Rng::with_seed(42);
+
+        // These must return right away instead of rolling every die.
+        assert_eq!(
+            roll_dice(&mut rng, "99999999999d6"),
+            Err(DiceError::TooManyDice)
+        );
+        assert_eq!(
+            roll_dice(&mut rng, "18446744073709551615d1"),
+            Err(DiceError::TooManyDice)
+        );
+        assert_eq!(
+            roll_dice(&mut rng, "2d9223372036854775807"),
+            Err(DiceError::Overflow)
+        );
+
+        let total = roll_dice(&mut rng, "10000d1").unwrap();
+        assert_eq!(total, 10_000);
+        assert_eq!(roll_dice(&mut rng, "10001d1"), Err(DiceError::TooManyDice));
+    }
+}
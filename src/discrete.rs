@@ -0,0 +1,446 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use crate::bernoulli::bool_with_prob;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::float_normal::{self, FloatMathExt};
+use crate::BaseRng;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{float_gamma, poisson};
+
+/// Up to this number of trials, binomial counts are sampled by running every
+/// trial.
+const BINOMIAL_DIRECT_MAX: u64 = 64;
+
+/// From this variance on, binomial counts are sampled from a normal
+/// approximation if it is available.
+#[cfg(any(feature = "std", feature = "libm"))]
+const BINOMIAL_NORMAL_MIN_VARIANCE: f64 = 9.0;
+
+pub(super) fn u64_geometric(rng: &mut impl BaseRng, p: f64) -> u64 {
+    debug_assert!(
+        p > 0.0 && p <= 1.0,
+        "probability must be in (0, 1], but got {}",
+        p
+    );
+    if p >= 1.0 {
+        return 0;
+    }
+    // A success never happens, so saturate. This also covers NaN.
+    if p.partial_cmp(&0.0) != Some(Ordering::Greater) {
+        return u64::MAX;
+    }
+
+    geometric_impl(rng, p)
+}
+
+pub(super) fn u64_binomial(rng: &mut impl BaseRng, n: u64, p: f64) -> u64 {
+    debug_assert!(
+        (0.0..=1.0).contains(&p),
+        "probability must be in [0, 1], but got {}",
+        p
+    );
+
+    if n <= BINOMIAL_DIRECT_MAX {
+        return (0..n).filter(|_| bool_with_prob(rng, p)).count() as u64;
+    }
+    if p <= 0.0 {
+        return 0;
+    }
+    if p >= 1.0 {
+        return n;
+    }
+    // Count failures instead of successes to keep the probability small.
+    if p > 0.5 {
+        return n - u64_binomial(rng, n, 1.0 - p);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    {
+        let mean = n as f64 * p;
+        let variance = mean * (1.0 - p);
+        if variance >= BINOMIAL_NORMAL_MIN_VARIANCE {
+            // Round to the nearest integer, which is the continuity
+            // correction. Negative values saturate to zero.
+            let value = float_normal::f64(rng, mean, FloatMathExt::sqrt(variance));
+            return saturating_u64(value + 0.5).min(n);
+        }
+    }
+
+    // Few successes are expected, so jump from one success to the next.
+    let mut successes = 0;
+    let mut trials = 0u64;
+    loop {
+        trials = trials
+            .saturating_add(geometric_impl(rng, p))
+            .saturating_add(1);
+        if trials > n {
+            return successes;
+        }
+        successes += 1;
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn multinomial(rng: &mut impl BaseRng, n: u64, probs: &[f64]) -> Vec<u64> {
+    assert!(
+        !probs.is_empty(),
+        "multinomial distribution requires outcomes"
+    );
+    debug_assert!(
+        probs.iter().all(|&p| p >= 0.0),
+        "probabilities must not be negative"
+    );
+
+    // Each count is binomial in the trials not yet assigned, with the
+    // probability of the outcome conditioned on not being one of the previous
+    // outcomes.
+    let mut remaining_n = n;
+    let mut remaining_p: f64 = probs.iter().sum();
+    let mut counts = Vec::with_capacity(probs.len());
+    for &p in probs[..probs.len() - 1].iter() {
+        let count = if remaining_n == 0 || remaining_p <= 0.0 {
+            0
+        } else {
+            u64_binomial(rng, remaining_n, (p / remaining_p).min(1.0))
+        };
+        counts.push(count);
+        remaining_n -= count;
+        remaining_p -= p;
+    }
+    // The last outcome takes the rest, so the counts sum to `n` regardless of
+    // rounding in the probabilities.
+    counts.push(remaining_n);
+
+    counts
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn u64_negative_binomial(rng: &mut impl BaseRng, r: f64, p: f64) -> u64 {
+    assert!(
+        r > 0.0 && p > 0.0 && p <= 1.0,
+        "negative binomial distribution requires r > 0 and 0 < p <= 1"
+    );
+    if p == 1.0 {
+        return 0;
+    }
+
+    // Gamma-Poisson mixture: a Poisson count whose rate is Gamma distributed.
+    let rate = float_gamma::f64_gamma(rng, r, (1.0 - p) / p);
+    poisson::u64_poisson(rng, rate)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn u64_zipf(rng: &mut impl BaseRng, n: u64, s: f64) -> u64 {
+    assert!(n > 0, "Zipf distribution requires n > 0");
+    assert!(s >= 0.0, "Zipf distribution requires s >= 0");
+
+    // Rejection-inversion by Hörmann and Derflinger, see "Rejection-inversion
+    // to generate variates from monotone discrete distributions". The
+    // continuous hat function `h(x) = x^-s` is integrated by `zipf_h_integral`
+    // and sampled by inverting the integral. Values under the hat but outside
+    // of the distribution are rejected.
+    let h = |x: f64| FloatMathExt::powf(x, -s);
+    let h_integral_x1 = zipf_h_integral(1.5, s) - 1.0;
+    let h_integral_n = zipf_h_integral(n as f64 + 0.5, s);
+    // Values of `x` this close to `k` are always accepted.
+    let squeeze = 2.0 - zipf_h_integral_inv(zipf_h_integral(2.5, s) - h(2.0), s);
+
+    loop {
+        let u = h_integral_n + rng.f64() * (h_integral_x1 - h_integral_n);
+        let x = zipf_h_integral_inv(u, s);
+        // The clamp guards against rounding at the ends.
+        let k = saturating_u64(x + 0.5).max(1).min(n);
+
+        if k as f64 - x <= squeeze || u >= zipf_h_integral(k as f64 + 0.5, s) - h(k as f64) {
+            return k;
+        }
+    }
+}
+
+/// `H(x) = (x^(1 - s) - 1) / (1 - s)`, or `ln(x)` for `s = 1`, computed
+/// without cancellation near `s = 1`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn zipf_h_integral(x: f64, s: f64) -> f64 {
+    let ln_x = FloatMathExt::ln(x);
+    exp_m1_div(ln_x * (1.0 - s)) * ln_x
+}
+
+/// The inverse of `zipf_h_integral`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn zipf_h_integral_inv(x: f64, s: f64) -> f64 {
+    let t = (x * (1.0 - s)).max(-1.0);
+    FloatMathExt::exp(ln_1p_div(t) * x)
+}
+
+/// `(e^x - 1) / x`, continuous at 0.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn exp_m1_div(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        FloatMathExt::exp_m1(x) / x
+    } else {
+        1.0 + x * 0.5 * (1.0 + x / 3.0)
+    }
+}
+
+/// `ln(1 + x) / x`, continuous at 0.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn ln_1p_div(x: f64) -> f64 {
+    if x.abs() > 1e-8 {
+        FloatMathExt::ln_1p(x) / x
+    } else {
+        1.0 - x * (0.5 - x / 3.0)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn geometric_impl(rng: &mut impl BaseRng, p: f64) -> u64 {
+    // Inverse transform sampling, rounding down. `1 - p` would round to 1 for
+    // tiny `p`, so the denominator is computed as `ln(1 + (-p))` directly.
+    let u = rng.f64_open();
+    saturating_u64(FloatMathExt::ln(u) / FloatMathExt::ln_1p(-p))
+}
+
+#[cfg(not(any(feature = "std", feature = "libm")))]
+fn geometric_impl(rng: &mut impl BaseRng, p: f64) -> u64 {
+    // Without logarithms, run the Bernoulli trials one by one.
+    let mut failures = 0;
+    while rng.f64() >= p {
+        failures += 1;
+    }
+    failures
+}
+
+/// Convert a float to `u64`, rounding towards zero. Negative values and NaN
+/// become zero and too large values become `u64::MAX`.
+// Float to integer `as` casts saturate only since Rust 1.45 and are undefined
+// behavior for out of range values before. Our current MSRV is 1.43.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn saturating_u64(x: f64) -> u64 {
+    // `u64::MAX as f64` rounds up to 2^64, which is out of range itself.
+    const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+
+    if x >= LIMIT {
+        u64::MAX
+    } else if x > 0.0 {
+        x as u64
+    } else {
+        // This branch also catches NaN.
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn saturating_u64_clamps() {
+        assert_eq!(saturating_u64(3.7), 3);
+        assert_eq!(saturating_u64(0.0), 0);
+        assert_eq!(saturating_u64(-5.0), 0);
+        assert_eq!(saturating_u64(f64::NEG_INFINITY), 0);
+        assert_eq!(saturating_u64(f64::NAN), 0);
+        assert_eq!(saturating_u64(1e30), u64::MAX);
+        assert_eq!(saturating_u64(f64::INFINITY), u64::MAX);
+        assert_eq!(saturating_u64(9_007_199_254_740_993.0), 1 << 53);
+    }
+
+    #[test]
+    fn geometric_mean_matches() {
+        let mut rng = Rng::with_seed(42);
+
+        for &p in [0.05, 0.3, 0.9].iter() {
+            let total = 100_000;
+            let sum: u64 = (0..total).map(|_| u64_geometric(&mut rng, p)).sum();
+            let mean = sum as f64 / total as f64;
+            let expected = (1.0 - p) / p;
+            assert!(
+                (mean - expected).abs() < 0.02 * expected + 0.01,
+                "mean should be ~{}, but is {}",
+                expected,
+                mean
+            );
+        }
+
+        assert_eq!(u64_geometric(&mut rng, 1.0), 0);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn geometric_handles_tiny_probability() {
+        let mut rng = Rng::with_seed(42);
+
+        // `1 - p` rounds to 1 here, which must not collapse all values to 0.
+        let p = 1e-17;
+        let total = 10_000;
+        let mean = (0..total)
+            .map(|_| u64_geometric(&mut rng, p) as f64)
+            .sum::<f64>()
+            / total as f64;
+        assert!(
+            (mean * p - 1.0).abs() < 0.05,
+            "mean should be ~{}, but is {}",
+            1.0 / p,
+            mean
+        );
+
+        // The expected value is far beyond `u64::MAX`.
+        for _ in 0..100 {
+            assert_eq!(u64_geometric(&mut rng, 1e-300), u64::MAX);
+        }
+    }
+
+    #[test]
+    fn binomial_mean_and_variance_match() {
+        let mut rng = Rng::with_seed(42);
+
+        // Direct trials, the normal approximation and the geometric jumps.
+        // Without logarithms, the jumps are as slow as running every trial.
+        let cases: &[(u64, f64)] = if cfg!(any(feature = "std", feature = "libm")) {
+            &[
+                (20, 0.3),
+                (64, 0.9),
+                (1000, 0.4),
+                (10_000, 0.9995),
+                (1_000_000, 2e-6),
+            ]
+        } else {
+            &[(20, 0.3), (64, 0.9), (1000, 0.002)]
+        };
+        for &(n, p) in cases.iter() {
+            let total = 100_000;
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..total {
+                let x = u64_binomial(&mut rng, n, p);
+                assert!(x <= n);
+                let x = x as f64;
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / total as f64;
+            let variance = sum_sq / total as f64 - mean * mean;
+
+            let expected_mean = n as f64 * p;
+            let expected_variance = expected_mean * (1.0 - p);
+            assert!(
+                (mean - expected_mean).abs() < 0.02 * expected_mean,
+                "mean should be ~{}, but is {}",
+                expected_mean,
+                mean
+            );
+            assert!(
+                (variance - expected_variance).abs() < 0.05 * expected_variance,
+                "variance should be ~{}, but is {}",
+                expected_variance,
+                variance
+            );
+        }
+
+        assert_eq!(u64_binomial(&mut rng, 1000, 0.0), 0);
+        assert_eq!(u64_binomial(&mut rng, 1000, 1.0), 1000);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn negative_binomial_mean_matches() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(r, p) in [(1.0, 0.5), (3.5, 0.2), (20.0, 0.9)].iter() {
+            let total = 100_000;
+            let sum: u64 = (0..total)
+                .map(|_| u64_negative_binomial(&mut rng, r, p))
+                .sum();
+            let mean = sum as f64 / total as f64;
+            let expected = r * (1.0 - p) / p;
+            assert!(
+                (mean - expected).abs() < 0.02 * expected,
+                "mean should be ~{}, but is {}",
+                expected,
+                mean
+            );
+        }
+
+        assert_eq!(u64_negative_binomial(&mut rng, 2.0, 1.0), 0);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn multinomial_counts_sum_to_n() {
+        let mut rng = Rng::with_seed(42);
+
+        let probs = [0.1, 0.0, 0.25, 0.4, 0.25];
+        // Without math functions, large binomials are sampled slowly.
+        let n = if cfg!(any(feature = "std", feature = "libm")) {
+            100_000
+        } else {
+            1_000
+        };
+        let rounds = 100;
+        let mut sums = [0u64; 5];
+        for _ in 0..rounds {
+            let counts = multinomial(&mut rng, n, &probs);
+            assert_eq!(counts.len(), probs.len());
+            assert_eq!(counts.iter().sum::<u64>(), n);
+            assert_eq!(counts[1], 0);
+            for (sum, count) in sums.iter_mut().zip(counts) {
+                *sum += count;
+            }
+        }
+
+        for (&sum, &p) in sums.iter().zip(probs.iter()) {
+            let mean = sum as f64 / rounds as f64;
+            let expected = n as f64 * p;
+            assert!(
+                (mean - expected).abs() <= 0.01 * n as f64,
+                "mean count should be ~{}, but is {}",
+                expected,
+                mean
+            );
+        }
+
+        assert_eq!(multinomial(&mut rng, 0, &probs), [0; 5]);
+        assert_eq!(multinomial(&mut rng, 7, &[2.0]), [7]);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn zipf_follows_power_law() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(n, s) in [(100, 1.2), (1000, 1.0), (10, 0.5), (5, 0.0)].iter() {
+            let total = 100_000;
+            let mut counts = [0; 5];
+            for _ in 0..total {
+                let k = u64_zipf(&mut rng, n, s);
+                assert!((1..=n).contains(&k));
+                if k <= 5 {
+                    counts[k as usize - 1] += 1;
+                }
+            }
+
+            let norm: f64 = (1..=n).map(|k| FloatMathExt::powf(k as f64, -s)).sum();
+            for (i, &count) in counts.iter().enumerate() {
+                let expected = total as f64 * FloatMathExt::powf((i + 1) as f64, -s) / norm;
+                assert!(
+                    (count as f64 - expected).abs() < 0.03 * expected + 50.0,
+                    "rank {} sampled {} times, expected ~{} (n = {}, s = {})",
+                    i + 1,
+                    count,
+                    expected,
+                    n,
+                    s
+                );
+            }
+            if s > 0.0 {
+                assert!(counts[0] > counts[1]);
+            }
+        }
+
+        assert_eq!(u64_zipf(&mut rng, 1, 2.0), 1);
+    }
+}
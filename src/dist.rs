@@ -0,0 +1,170 @@
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::marker::PhantomData;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::{float_exponential, float_normal};
+use crate::{float_range, Rng};
+
+/// A probability distribution of values of type `T`.
+///
+/// This allows writing code that is generic over distributions or storing
+/// them as trait objects, e.g. `Box<dyn Distribution<f64>>`.
+pub trait Distribution<T> {
+    /// Sample a value from the distribution.
+    fn sample(&self, rng: &mut Rng) -> T;
+}
+
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for &D {
+    #[inline]
+    fn sample(&self, rng: &mut Rng) -> T {
+        (**self).sample(rng)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T, D: Distribution<T> + ?Sized> Distribution<T> for Box<D> {
+    #[inline]
+    fn sample(&self, rng: &mut Rng) -> T {
+        (**self).sample(rng)
+    }
+}
+
+/// Extension methods for all [`Distribution`]s.
+pub trait DistExt<T>: Distribution<T> + Sized {
+    /// Create an iterator that samples values from the distribution forever.
+    fn sample_iter(self, rng: &mut Rng) -> DistIter<'_, Self, T> {
+        DistIter {
+            dist: self,
+            rng,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T, D: Distribution<T>> DistExt<T> for D {}
+
+/// An iterator that samples values from a distribution forever.
+///
+/// Created by [`sample_iter`](DistExt::sample_iter).
+#[derive(Debug)]
+pub struct DistIter<'a, D, T> {
+    dist: D,
+    rng: &'a mut Rng,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<D: Distribution<T>, T> Iterator for DistIter<'_, D, T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some(self.dist.sample(self.rng))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// The uniform distribution over `[low, high)`.
+///
+/// Sampling panics if the range can't be sampled from, see
+/// [`RangeError`](crate::RangeError).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Uniform {
+    /// The lower bound.
+    pub low: f64,
+    /// The upper bound.
+    pub high: f64,
+}
+
+impl Distribution<f64> for Uniform {
+    #[inline]
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        float_range::f64(rng, self.low..self.high)
+    }
+}
+
+/// The normal distribution with mean `mu` and standard deviation `sigma`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normal {
+    /// The mean.
+    pub mu: f64,
+    /// The standard deviation.
+    pub sigma: f64,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Distribution<f64> for Normal {
+    #[inline]
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        float_normal::f64(rng, self.mu, self.sigma)
+    }
+}
+
+/// The exponential distribution with rate `lambda`.
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Exponential {
+    /// The rate, i.e. the inverse of the mean.
+    pub lambda: f64,
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Distribution<f64> for Exponential {
+    #[inline]
+    fn sample(&self, rng: &mut Rng) -> f64 {
+        float_exponential::f64(rng, self.lambda)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uniform_samples_through_iterator() {
+        let mut rng = Rng::with_seed(42);
+        let mut expected = Rng::with_seed(42);
+
+        let uniform = Uniform {
+            low: -1.0,
+            high: 2.0,
+        };
+        for x in (&uniform).sample_iter(&mut rng).take(100) {
+            assert_eq!(x, float_range::f64(&mut expected, -1.0..2.0));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn normal_samples_through_trait_object() {
+        use alloc::vec::Vec;
+
+        let mut rng = Rng::with_seed(42);
+        let mut expected = Rng::with_seed(42);
+
+        let dists: Vec<Box<dyn Distribution<f64>>> = alloc::vec![
+            Box::new(Normal {
+                mu: 10.0,
+                sigma: 3.0,
+            }),
+            Box::new(Exponential { lambda: 2.0 }),
+        ];
+        assert_eq!(
+            dists[0].sample(&mut rng),
+            float_normal::f64(&mut expected, 10.0, 3.0)
+        );
+        assert_eq!(
+            dists[1].sample(&mut rng),
+            float_exponential::f64(&mut expected, 2.0)
+        );
+
+        let total = 10_000;
+        let mean = (&dists[0]).sample_iter(&mut rng).take(total).sum::<f64>() / total as f64;
+        assert!((mean - 10.0).abs() < 0.1, "mean is {}", mean);
+    }
+}
@@ -0,0 +1,54 @@
+use crate::float_normal::FloatMathExt;
+
+/// Compute the empirical Shannon entropy of a byte sequence in bits per byte.
+///
+/// The result is in the `[0, 8]` range. Uniformly random bytes have an entropy
+/// close to 8, while a constant sequence has an entropy of 0. This is useful to
+/// detect accidentally degenerate random data in tests.
+///
+/// Returns 0 for an empty sequence.
+///
+/// ```
+/// use fastrand_contrib::shannon_entropy;
+///
+/// assert_eq!(shannon_entropy(b"aaaa"), 0.0);
+/// assert_eq!(shannon_entropy(b"abab"), 1.0);
+/// ```
+pub fn shannon_entropy(samples: &[u8]) -> f64 {
+    let mut counts = [0usize; 256];
+    for &byte in samples {
+        counts[byte as usize] += 1;
+    }
+
+    let total = samples.len() as f64;
+    let entropy = counts
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * FloatMathExt::ln(p)
+        })
+        .sum::<f64>();
+
+    entropy / core::f64::consts::LN_2
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn shannon_entropy_bounds() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut bytes = [0; 65536];
+        rng.fill(&mut bytes);
+        let entropy = shannon_entropy(&bytes);
+        assert!(entropy > 7.99 && entropy <= 8.0, "entropy is {}", entropy);
+
+        assert_eq!(shannon_entropy(&[7; 100]), 0.0);
+        assert_eq!(shannon_entropy(&[]), 0.0);
+    }
+}
@@ -0,0 +1,118 @@
+use core::ops::RangeBounds;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::float_normal;
+use crate::float_range;
+use crate::BaseRng;
+
+pub(super) fn fill_bytes(rng: &mut impl BaseRng, buf: &mut [u8]) {
+    // The last chunk is shorter if the length is not a multiple of 8 and takes
+    // only the low bytes of the random number.
+    for chunk in buf.chunks_mut(8) {
+        let random = rng.u64().to_le_bytes();
+        chunk.copy_from_slice(&random[..chunk.len()]);
+    }
+}
+
+pub(super) fn fill_f32(rng: &mut impl BaseRng, buf: &mut [f32]) {
+    for x in buf.iter_mut() {
+        *x = rng.f32();
+    }
+}
+
+pub(super) fn fill_f64(rng: &mut impl BaseRng, buf: &mut [f64]) {
+    for x in buf.iter_mut() {
+        *x = rng.f64();
+    }
+}
+
+pub(super) fn fill_f64_range(
+    rng: &mut impl BaseRng,
+    buf: &mut [f64],
+    range: impl RangeBounds<f64>,
+) {
+    float_range::fill_f64(rng, buf, range)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn fill_f64_normal(rng: &mut impl BaseRng, buf: &mut [f64], mu: f64, sigma: f64) {
+    float_normal::fill_f64(rng, buf, mu, sigma)
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn fill_bytes_is_deterministic() {
+        let mut a = [0u8; 29];
+        let mut b = [0u8; 29];
+        fill_bytes(&mut Rng::with_seed(42), &mut a);
+        fill_bytes(&mut Rng::with_seed(42), &mut b);
+        assert_eq!(a, b);
+        assert!(a.iter().any(|&byte| byte != 0));
+
+        let mut rng = Rng::with_seed(42);
+        let mut other = Rng::with_seed(42);
+        fill_bytes(&mut rng, &mut []);
+        assert_eq!(
+            rng.u64(..),
+            other.u64(..),
+            "empty fill must not use the RNG"
+        );
+    }
+
+    #[test]
+    fn fill_f64_range_stays_in_range() {
+        let mut rng = Rng::with_seed(42);
+        let mut buf = [0.0; 1000];
+
+        fill_f64_range(&mut rng, &mut buf, -3.0..5.0);
+        assert!(buf.iter().all(|x| (-3.0..5.0).contains(x)));
+
+        fill_f64_range(&mut rng, &mut buf, 1.0..=2.0);
+        assert!(buf.iter().all(|x| (1.0..=2.0).contains(x)));
+
+        fill_f64_range(&mut rng, &mut buf, 4.0..=4.0);
+        assert!(buf.iter().all(|&x| x == 4.0));
+
+        fill_f64_range(&mut rng, &mut buf, ..);
+        assert!(buf.iter().all(|x| x.is_finite()));
+    }
+
+    #[test]
+    fn fill_f32_and_f64_stay_in_unit_interval() {
+        let mut rng = Rng::with_seed(42);
+        let mut buf32 = [-1.0; 1000];
+        let mut buf64 = [-1.0; 1000];
+
+        fill_f32(&mut rng, &mut buf32);
+        fill_f64(&mut rng, &mut buf64);
+
+        assert!(buf32.iter().all(|x| (0.0..1.0).contains(x)));
+        assert!(buf64.iter().all(|x| (0.0..1.0).contains(x)));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn fill_f64_normal_has_expected_moments() {
+        let mut rng = Rng::with_seed(42);
+
+        // An odd length also exercises the unpaired last element.
+        let mut buf = [0.0; 100_001];
+        fill_f64_normal(&mut rng, &mut buf, 10.0, 3.0);
+
+        let n = buf.len() as f64;
+        let mean = buf.iter().sum::<f64>() / n;
+        let variance = buf.iter().map(|x| (x - mean) * (x - mean)).sum::<f64>() / n;
+        assert!((mean - 10.0).abs() < 0.05, "mean is {}", mean);
+        assert!(
+            (variance.sqrt() - 3.0).abs() < 0.05,
+            "standard deviation is {}",
+            variance.sqrt()
+        );
+        assert!(buf[buf.len() - 1] != 0.0);
+    }
+}
@@ -0,0 +1,52 @@
+use crate::BaseRng;
+
+pub(super) fn f64_bits(rng: &mut impl BaseRng) -> u64 {
+    rng.u64()
+}
+
+/// Convert 64 random bits into a 64-bit floating point number in the `[0, 1)`
+/// range.
+///
+/// Only the upper 53 bits are used, which are scaled onto a uniform grid with
+/// a step of 2<sup>-53</sup>. Together with [`RngExt::f64_bits`], this allows
+/// to reconstruct a generated float exactly or to feed the same bits
+/// elsewhere.
+///
+/// The conversion is defined by this crate and does not change between
+/// versions of [`fastrand`]. Note that it is not the same conversion that
+/// `fastrand::Rng::f64` uses internally.
+///
+/// [`RngExt::f64_bits`]: crate::RngExt::f64_bits
+/// [`fastrand`]: https://crates.io/crates/fastrand
+///
+/// ```
+/// use fastrand_contrib::f64_from_bits;
+///
+/// assert_eq!(f64_from_bits(0), 0.0);
+/// assert!(f64_from_bits(u64::MAX) < 1.0);
+/// ```
+pub fn f64_from_bits(bits: u64) -> f64 {
+    const SCALE: f64 = 1.0 / (1u64 << 53) as f64;
+    (bits >> 11) as f64 * SCALE
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn f64_from_bits_reconstructs_float() {
+        let mut rng = Rng::with_seed(42);
+        let mut copy = rng.clone();
+
+        for _ in 0..10000 {
+            let bits = f64_bits(&mut rng);
+            assert_eq!(bits, copy.u64(..));
+
+            let x = f64_from_bits(bits);
+            assert!((0.0..1.0).contains(&x));
+        }
+    }
+}
@@ -0,0 +1,556 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::f64::consts::PI;
+use core::time::Duration;
+
+use crate::float_normal::FloatMathExt;
+use crate::BaseRng;
+
+pub(super) fn f64_standard_exponential(rng: &mut impl BaseRng) -> f64 {
+    // Inverse transform sampling. `1 - U` is in (0, 1], so the logarithm is
+    // always finite.
+    -FloatMathExt::ln(1.0 - rng.f64())
+}
+
+pub(super) fn exponential_duration(rng: &mut impl BaseRng, mean: Duration) -> Duration {
+    assert!(mean != Duration::from_secs(0), "mean must not be zero");
+
+    let secs = f64_standard_exponential(rng) * mean.as_secs_f64();
+    // `Duration::MAX` was stabilized in Rust 1.53. Our current MSRV is 1.43.
+    if secs >= u64::MAX as f64 {
+        Duration::new(u64::MAX, 999_999_999)
+    } else {
+        Duration::from_secs_f64(secs)
+    }
+}
+
+pub(super) fn f64_standard_cauchy(rng: &mut impl BaseRng) -> f64 {
+    FloatMathExt::tan(PI * (rng.f64_open() - 0.5))
+}
+
+pub(super) fn f32_weibull(rng: &mut impl BaseRng, shape: f32, scale: f32) -> f32 {
+    f64_weibull(rng, shape as f64, scale as f64) as f32
+}
+
+pub(super) fn f64_weibull(rng: &mut impl BaseRng, shape: f64, scale: f64) -> f64 {
+    assert!(
+        shape > 0.0 && scale > 0.0,
+        "Weibull distribution requires shape > 0 and scale > 0"
+    );
+
+    // Inverse transform sampling: a standard exponential variate raised to
+    // `1 / shape` is Weibull distributed.
+    scale * FloatMathExt::powf(f64_standard_exponential(rng), 1.0 / shape)
+}
+
+pub(super) fn f64_pareto(rng: &mut impl BaseRng, scale: f64, shape: f64) -> f64 {
+    assert!(
+        scale > 0.0 && shape > 0.0,
+        "Pareto distribution requires scale > 0 and shape > 0"
+    );
+
+    // Inverse transform sampling with `1 - U` in (0, 1], so the divisor is at
+    // most 1 and never 0.
+    scale / FloatMathExt::powf(1.0 - rng.f64(), 1.0 / shape)
+}
+
+pub(super) fn f32_laplace(rng: &mut impl BaseRng, mu: f32, b: f32) -> f32 {
+    f64_laplace(rng, mu as f64, b as f64) as f32
+}
+
+pub(super) fn f64_laplace(rng: &mut impl BaseRng, mu: f64, b: f64) -> f64 {
+    // Inverse transform sampling with `u` in (-0.5, 0.5), so the argument of
+    // the logarithm is in (0, 1].
+    let u = rng.f64_open() - 0.5;
+    let magnitude = -b * FloatMathExt::ln(1.0 - 2.0 * u.abs());
+    if u < 0.0 {
+        mu - magnitude
+    } else {
+        mu + magnitude
+    }
+}
+
+pub(super) fn f64_gumbel(rng: &mut impl BaseRng, loc: f64, scale: f64) -> f64 {
+    // Inverse transform sampling. `u` is in (0, 1), so `-ln(u)` is positive
+    // and both logarithms are finite.
+    let u = rng.f64_open();
+    loc - scale * FloatMathExt::ln(-FloatMathExt::ln(u))
+}
+
+pub(super) fn f32_rayleigh(rng: &mut impl BaseRng, sigma: f32) -> f32 {
+    f64_rayleigh(rng, sigma as f64) as f32
+}
+
+pub(super) fn f64_rayleigh(rng: &mut impl BaseRng, sigma: f64) -> f64 {
+    assert!(sigma >= 0.0, "Rayleigh distribution requires sigma >= 0");
+
+    // The square root of twice a standard exponential variate, which is
+    // always finite and non-negative.
+    sigma * FloatMathExt::sqrt(2.0 * f64_standard_exponential(rng))
+}
+
+pub(super) fn f32_cauchy(rng: &mut impl BaseRng, median: f32, scale: f32) -> f32 {
+    f64_cauchy(rng, median as f64, scale as f64) as f32
+}
+
+pub(super) fn f64_cauchy(rng: &mut impl BaseRng, median: f64, scale: f64) -> f64 {
+    median + scale * f64_standard_cauchy(rng)
+}
+
+pub(super) fn f64_standard_logistic(rng: &mut impl BaseRng) -> f64 {
+    let u = rng.f64_open();
+    FloatMathExt::ln(u / (1.0 - u))
+}
+
+pub(super) fn f32_logistic(rng: &mut impl BaseRng, location: f32, scale: f32) -> f32 {
+    f64_logistic(rng, location as f64, scale as f64) as f32
+}
+
+pub(super) fn f64_logistic(rng: &mut impl BaseRng, location: f64, scale: f64) -> f64 {
+    location + scale * f64_standard_logistic(rng)
+}
+
+pub(super) fn f64_von_mises(rng: &mut impl BaseRng, mu: f64, kappa: f64) -> f64 {
+    assert!(kappa >= 0.0, "von Mises distribution requires kappa >= 0");
+
+    // The distribution degenerates to the uniform one and the algorithm below
+    // would divide by zero.
+    let theta = if kappa < 1e-9 {
+        PI * (2.0 * rng.f64() - 1.0)
+    } else {
+        // Best & Fisher's rejection algorithm with a wrapped Cauchy envelope.
+        let tau = 1.0 + FloatMathExt::sqrt(1.0 + 4.0 * kappa * kappa);
+        let rho = (tau - FloatMathExt::sqrt(2.0 * tau)) / (2.0 * kappa);
+        let r = (1.0 + rho * rho) / (2.0 * rho);
+
+        let f = loop {
+            let z = FloatMathExt::cos(PI * rng.f64());
+            let f = (1.0 + r * z) / (r + z);
+            let c = kappa * (r - f);
+            let u = rng.f64();
+
+            if c * (2.0 - c) > u || FloatMathExt::ln(c / u) + 1.0 >= c {
+                break f;
+            }
+        };
+
+        // Rounding may push `f` slightly out of the domain of `acos`.
+        let angle = FloatMathExt::acos(f.max(-1.0).min(1.0));
+        if rng.bool() {
+            angle
+        } else {
+            -angle
+        }
+    };
+
+    // Wrap the angle into (-pi, pi].
+    let mut x = (mu + theta + PI) % (2.0 * PI);
+    if x <= 0.0 {
+        x += 2.0 * PI;
+    }
+    x - PI
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn nonhomogeneous_poisson(
+    rng: &mut impl BaseRng,
+    duration: f64,
+    max_rate: f64,
+    rate_fn: impl Fn(f64) -> f64,
+) -> Vec<f64> {
+    assert!(duration > 0.0, "duration must be positive");
+    assert!(max_rate > 0.0, "max rate must be positive");
+
+    // Lewis and Shedler thinning: generate candidate events of a homogeneous
+    // process with rate `max_rate` and keep each with probability
+    // `rate_fn(t) / max_rate`.
+    let mut events = Vec::new();
+    let mut t = 0.0;
+    loop {
+        t += f64_standard_exponential(rng) / max_rate;
+        if t >= duration {
+            return events;
+        }
+
+        let rate = rate_fn(t);
+        debug_assert!(
+            rate <= max_rate,
+            "rate function must not exceed the max rate"
+        );
+        if rng.f64() * max_rate < rate {
+            events.push(t);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    /// Check that the fraction of samples below `x` matches the cumulative
+    /// distribution function at `x`.
+    fn assert_cdf(rng: &mut Rng, sample: impl Fn(&mut Rng) -> f64, x: f64, expected: f64) {
+        let total = 10000;
+        let below = (0..total).filter(|_| sample(rng) < x).count();
+        let below = below as f64 / total as f64;
+        assert!(
+            (below - expected).abs() < 0.02,
+            "P(X < {}) should be ~{}, but is {}",
+            x,
+            expected,
+            below
+        );
+    }
+
+    /// Check that two samplers produce the same values for the same seed.
+    fn assert_same_samples(a: impl Fn(&mut Rng) -> f64, b: impl Fn(&mut Rng) -> f64) {
+        let mut rng_a = Rng::with_seed(42);
+        let mut rng_b = Rng::with_seed(42);
+        for _ in 0..1000 {
+            assert_eq!(a(&mut rng_a), b(&mut rng_b));
+        }
+    }
+
+    #[test]
+    fn standard_distributions_match_parameterized_ones() {
+        assert_same_samples(f64_standard_exponential, |rng| {
+            crate::float_exponential::f64(rng, 1.0)
+        });
+        assert_same_samples(f64_standard_cauchy, |rng| f64_cauchy(rng, 0.0, 1.0));
+        assert_same_samples(f64_standard_logistic, |rng| f64_logistic(rng, 0.0, 1.0));
+    }
+
+    #[test]
+    fn standard_exponential_follows_cdf() {
+        let mut rng = Rng::with_seed(42);
+
+        for &x in [0.1, 1.0, 3.0].iter() {
+            assert_cdf(
+                &mut rng,
+                f64_standard_exponential,
+                x,
+                1.0 - FloatMathExt::exp(-x),
+            );
+        }
+    }
+
+    #[test]
+    fn exponential_duration_has_requested_mean() {
+        let mut rng = Rng::with_seed(42);
+
+        let mean = Duration::from_millis(250);
+        let total = 10000;
+        let sum: Duration = (0..total)
+            .map(|_| exponential_duration(&mut rng, mean))
+            .sum();
+        let sample_mean = sum.as_secs_f64() / total as f64;
+        assert!(
+            (sample_mean - 0.25).abs() < 0.01,
+            "mean should be ~0.25s, but is {}s",
+            sample_mean
+        );
+
+        let huge = Duration::new(u64::MAX, 0);
+        for _ in 0..100 {
+            assert!(exponential_duration(&mut rng, huge) > Duration::from_secs(0));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "mean must not be zero")]
+    fn exponential_duration_rejects_zero_mean() {
+        exponential_duration(&mut Rng::with_seed(42), Duration::from_secs(0));
+    }
+
+    #[test]
+    fn standard_cauchy_follows_cdf() {
+        let mut rng = Rng::with_seed(42);
+
+        assert_cdf(&mut rng, f64_standard_cauchy, -1.0, 0.25);
+        assert_cdf(&mut rng, f64_standard_cauchy, 0.0, 0.5);
+        assert_cdf(&mut rng, f64_standard_cauchy, 1.0, 0.75);
+    }
+
+    #[test]
+    fn weibull_follows_cdf() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(shape, scale) in [(0.5, 1.0), (1.5, 2.0), (5.0, 10.0)].iter() {
+            let sample = |rng: &mut Rng| f64_weibull(rng, shape, scale);
+            let median = scale * FloatMathExt::powf(FloatMathExt::ln(2.0), 1.0 / shape);
+            assert_cdf(&mut rng, sample, median, 0.5);
+            assert_cdf(&mut rng, sample, scale, 1.0 - FloatMathExt::exp(-1.0));
+        }
+    }
+
+    #[test]
+    fn pareto_has_expected_minimum_and_mean() {
+        let mut rng = Rng::with_seed(42);
+
+        let (scale, shape) = (2.0, 3.0);
+        let total = 100_000;
+        let mut sum = 0.0;
+        let mut min = f64::INFINITY;
+        for _ in 0..total {
+            let value = f64_pareto(&mut rng, scale, shape);
+            assert!(value >= scale);
+            sum += value;
+            min = min.min(value);
+        }
+
+        assert!(min - scale < 0.001, "minimum is {}", min);
+        let mean = sum / total as f64;
+        let expected = shape * scale / (shape - 1.0);
+        assert!(
+            (mean - expected).abs() < 0.02 * expected,
+            "mean should be ~{}, but is {}",
+            expected,
+            mean
+        );
+    }
+
+    #[test]
+    fn laplace_is_symmetric_with_expected_variance() {
+        let mut rng = Rng::with_seed(42);
+
+        let (mu, b) = (3.0, 1.5);
+        let total = 100_000;
+        let mut sum_sq = 0.0;
+        let mut below_mu = 0;
+        let mut far_below = 0;
+        let mut far_above = 0;
+        for _ in 0..total {
+            let value = f64_laplace(&mut rng, mu, b);
+            sum_sq += (value - mu) * (value - mu);
+            if value < mu {
+                below_mu += 1;
+            }
+            if value < mu - 2.0 * b {
+                far_below += 1;
+            } else if value > mu + 2.0 * b {
+                far_above += 1;
+            }
+        }
+
+        let below_mu = below_mu as f64 / total as f64;
+        assert!((below_mu - 0.5).abs() < 0.01, "{}", below_mu);
+        // Each tail beyond `2 b` has the probability `e^-2 / 2`, about 6.8%.
+        assert!((far_below as f64 - far_above as f64).abs() < 0.01 * total as f64);
+
+        let variance = sum_sq / total as f64;
+        let expected = 2.0 * b * b;
+        assert!(
+            (variance - expected).abs() < 0.03 * expected,
+            "variance should be ~{}, but is {}",
+            expected,
+            variance
+        );
+    }
+
+    #[test]
+    fn gumbel_mode_is_loc() {
+        let mut rng = Rng::with_seed(42);
+
+        // Histogram with bins of width 0.25 over [loc - 5, loc + 5).
+        let (loc, scale) = (2.0, 1.0);
+        let mut bins = [0; 40];
+        for _ in 0..100_000 {
+            let value = f64_gumbel(&mut rng, loc, scale);
+            let offset = value - loc + 5.0;
+            if (0.0..10.0).contains(&offset) {
+                bins[(offset / 0.25) as usize] += 1;
+            }
+        }
+
+        // The mode is at `loc`, which is the boundary between bins 19 and 20.
+        let mode = (0..bins.len()).max_by_key(|&i| bins[i]).unwrap();
+        assert!((19..=20).contains(&mode), "mode is in bin {}", mode);
+    }
+
+    #[test]
+    fn rayleigh_has_expected_mode_and_mean() {
+        let mut rng = Rng::with_seed(42);
+
+        // Histogram with bins of width 0.25 over [0, 10).
+        let sigma = 2.0;
+        let total = 100_000;
+        let mut sum = 0.0;
+        let mut bins = [0; 40];
+        for _ in 0..total {
+            let value = f64_rayleigh(&mut rng, sigma);
+            assert!(value >= 0.0);
+            sum += value;
+            if value < 10.0 {
+                bins[(value / 0.25) as usize] += 1;
+            }
+        }
+
+        // The mode is at `sigma`, which is the boundary between bins 7 and 8.
+        let mode = (0..bins.len()).max_by_key(|&i| bins[i]).unwrap();
+        assert!((7..=8).contains(&mode), "mode is in bin {}", mode);
+
+        let mean = sum / total as f64;
+        let expected = sigma * FloatMathExt::sqrt(PI / 2.0);
+        assert!(
+            (mean - expected).abs() < 0.01 * expected,
+            "mean should be ~{}, but is {}",
+            expected,
+            mean
+        );
+    }
+
+    #[test]
+    fn cauchy_has_expected_median_and_quartiles() {
+        let mut rng = Rng::with_seed(42);
+
+        // The quartiles of the Cauchy distribution are `median ± scale`.
+        let (median, scale) = (5.0, 2.0);
+        let total = 100_000;
+        let mut below_median = 0;
+        let mut within_scale = 0;
+        for _ in 0..total {
+            let value = f64_cauchy(&mut rng, median, scale);
+            if value < median {
+                below_median += 1;
+            }
+            if (value - median).abs() <= scale {
+                within_scale += 1;
+            }
+        }
+
+        let below_median = below_median as f64 / total as f64;
+        let within_scale = within_scale as f64 / total as f64;
+        assert!((below_median - 0.5).abs() < 0.01, "{}", below_median);
+        assert!((within_scale - 0.5).abs() < 0.01, "{}", within_scale);
+    }
+
+    #[test]
+    fn standard_logistic_follows_cdf() {
+        let mut rng = Rng::with_seed(42);
+
+        for &x in [-2.0, 0.0, 1.0].iter() {
+            assert_cdf(
+                &mut rng,
+                f64_standard_logistic,
+                x,
+                1.0 / (1.0 + FloatMathExt::exp(-x)),
+            );
+        }
+    }
+
+    #[test]
+    fn logistic_is_symmetric_with_expected_variance() {
+        let mut rng = Rng::with_seed(42);
+
+        let (location, scale) = (3.0, 2.0);
+        let total = 200_000;
+        let mut below = 0;
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        for _ in 0..total {
+            let value = f64_logistic(&mut rng, location, scale);
+            if value < location {
+                below += 1;
+            }
+            sum += value - location;
+            sum_sq += (value - location) * (value - location);
+        }
+
+        let below = below as f64 / total as f64;
+        assert!((below - 0.5).abs() < 0.005, "{} below location", below);
+        let mean = sum / total as f64;
+        assert!(mean.abs() < 0.03, "mean offset is {}", mean);
+        let variance = sum_sq / total as f64 - mean * mean;
+        let expected = PI * PI / 3.0 * scale * scale;
+        assert!(
+            (variance - expected).abs() < 0.03 * expected,
+            "variance should be ~{}, but is {}",
+            expected,
+            variance
+        );
+
+        let value = f32_logistic(&mut rng, 1.0, 0.5);
+        assert!(value.is_finite());
+    }
+
+    #[test]
+    fn f64_von_mises_concentrates_around_mu() {
+        let mut rng = Rng::with_seed(42);
+
+        // With mu near pi, the samples wrap around to the negative side.
+        let (mu, kappa) = (3.0, 50.0);
+        let total = 100_000;
+        let mut sum_cos = 0.0;
+        let mut sum_sin = 0.0;
+        for _ in 0..total {
+            let x = f64_von_mises(&mut rng, mu, kappa);
+            assert!(x > -PI && x <= PI);
+            sum_cos += FloatMathExt::cos(x - mu);
+            sum_sin += FloatMathExt::sin(x - mu);
+        }
+
+        // The mean resultant length is I1(kappa) / I0(kappa), which is
+        // 1 - 1 / (2 kappa) - 1 / (8 kappa^2) up to higher order terms.
+        let expected = 1.0 - 1.0 / (2.0 * kappa) - 1.0 / (8.0 * kappa * kappa);
+        let mean_cos = sum_cos / total as f64;
+        assert!(
+            (mean_cos - expected).abs() < 0.001,
+            "mean cos is {}, expected ~{}",
+            mean_cos,
+            expected
+        );
+        assert!((sum_sin / total as f64).abs() < 0.002);
+    }
+
+    #[test]
+    fn f64_von_mises_is_uniform_without_concentration() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 80_000;
+        let mut counts = [0; 8];
+        for _ in 0..total {
+            let x = f64_von_mises(&mut rng, 1.0, 0.0);
+            assert!(x > -PI && x <= PI);
+            counts[(((x + PI) / (2.0 * PI)) * 8.0) as usize % 8] += 1;
+        }
+
+        let expected = total / 8;
+        for &count in counts.iter() {
+            assert!(
+                count > expected * 95 / 100 && count < expected * 105 / 100,
+                "bin sampled {} times, expected ~{}",
+                count,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn nonhomogeneous_poisson_follows_rate() {
+        let mut rng = Rng::with_seed(42);
+
+        // With rate `t` on [0, 10], the expected number of events is 12.5 in
+        // the first half and 37.5 in the second half.
+        let mut early = 0;
+        let mut late = 0;
+        for _ in 0..100 {
+            let events = nonhomogeneous_poisson(&mut rng, 10.0, 10.0, |t| t);
+            assert!(events.windows(2).all(|w| w[0] < w[1]));
+            assert!(events.iter().all(|&t| t > 0.0 && t < 10.0));
+            early += events.iter().filter(|&&t| t < 5.0).count();
+            late += events.iter().filter(|&&t| t >= 5.0).count();
+        }
+        assert!((1100..1400).contains(&early), "{} early events", early);
+        assert!((3500..4000).contains(&late), "{} late events", late);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "duration must be positive")]
+    fn nonhomogeneous_poisson_rejects_empty_duration() {
+        nonhomogeneous_poisson(&mut Rng::with_seed(42), 0.0, 1.0, |_| 1.0);
+    }
+}
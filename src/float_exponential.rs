@@ -0,0 +1,99 @@
+use core::cmp::Ordering;
+
+use crate::float_dist;
+use crate::float_normal::FloatMathExt;
+use crate::BaseRng;
+
+pub(super) fn f32(rng: &mut impl BaseRng, lambda: f32) -> f32 {
+    float_exponential_impl(rng, lambda)
+}
+
+pub(super) fn f64(rng: &mut impl BaseRng, lambda: f64) -> f64 {
+    float_exponential_impl(rng, lambda)
+}
+
+fn float_exponential_impl<T: FloatMathExt>(rng: &mut impl BaseRng, lambda: T) -> T {
+    let zero = T::from_f64(0.0);
+    debug_assert!(lambda > zero, "lambda must be positive");
+    // A NaN `lambda` is unordered and also ends up here.
+    if lambda.partial_cmp(&zero) != Some(Ordering::Greater) {
+        return T::INFINITY;
+    }
+
+    // Scale the standard exponential distribution, so that all exponential
+    // samplers produce the same values for the same seed.
+    T::from_f64(float_dist::f64_standard_exponential(rng)) / lambda
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn exponential_is_actually_exponential() {
+        let mut rng = Rng::with_seed(42);
+
+        let lambda = 4.0_f64;
+        let mean = 1.0 / lambda;
+
+        let total = 10000;
+        let mut sum = 0.0;
+        let mut below_mean = 0;
+        for _ in 0..total {
+            let value = float_exponential_impl(&mut rng, lambda);
+            assert!(value >= 0.0 && value.is_finite());
+
+            sum += value;
+            if value < mean {
+                below_mean += 1;
+            }
+        }
+
+        let sample_mean = sum / total as f64;
+        assert!(
+            (sample_mean - mean).abs() < 0.01,
+            "mean should be ~{}, but is {}",
+            mean,
+            sample_mean
+        );
+
+        let below_mean = below_mean as f64 / total as f64 * 100.0;
+        assert!(
+            (61.0..=65.5).contains(&below_mean),
+            "value below mean should be sampled ~63.2%, but is {}%",
+            below_mean
+        );
+    }
+
+    #[test]
+    fn exponential_scales_standard_exponential() {
+        let mut rng = Rng::with_seed(42);
+        let mut expected = Rng::with_seed(42);
+
+        for _ in 0..100 {
+            let standard = float_dist::f64_standard_exponential(&mut expected);
+            assert_eq!(f64(&mut rng, 4.0), standard / 4.0);
+            let standard = float_dist::f64_standard_exponential(&mut expected);
+            assert_eq!(f32(&mut rng, 4.0), standard as f32 / 4.0);
+        }
+    }
+
+    #[test]
+    #[cfg(not(debug_assertions))]
+    fn exponential_with_invalid_lambda_is_infinite() {
+        let mut rng = Rng::with_seed(42);
+
+        assert_eq!(float_exponential_impl(&mut rng, 0.0_f32), f32::INFINITY);
+        assert_eq!(float_exponential_impl(&mut rng, -1.0_f64), f64::INFINITY);
+        assert_eq!(float_exponential_impl(&mut rng, f64::NAN), f64::INFINITY);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "lambda must be positive")]
+    fn exponential_with_invalid_lambda_panics_in_debug() {
+        float_exponential_impl(&mut Rng::with_seed(42), 0.0_f64);
+    }
+}
@@ -0,0 +1,429 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use crate::float_normal::{self, FloatMathExt};
+use crate::BaseRng;
+
+pub(super) fn f32_gamma(rng: &mut impl BaseRng, shape: f32, scale: f32) -> f32 {
+    f64_gamma(rng, shape as f64, scale as f64) as f32
+}
+
+pub(super) fn f64_gamma(rng: &mut impl BaseRng, shape: f64, scale: f64) -> f64 {
+    assert!(
+        shape > 0.0 && scale > 0.0,
+        "Gamma distribution requires shape > 0 and scale > 0"
+    );
+
+    gamma_impl(rng, shape) * scale
+}
+
+pub(super) fn f32_chi_squared(rng: &mut impl BaseRng, k: f32) -> f32 {
+    f64_chi_squared(rng, k as f64) as f32
+}
+
+pub(super) fn f64_chi_squared(rng: &mut impl BaseRng, k: f64) -> f64 {
+    assert!(k > 0.0, "chi-squared distribution requires k > 0");
+
+    // Chi-squared(k) is Gamma(k / 2, 2).
+    gamma_impl(rng, k / 2.0) * 2.0
+}
+
+pub(super) fn f64_students_t(rng: &mut impl BaseRng, nu: f64) -> f64 {
+    assert!(nu > 0.0, "Student's t distribution requires nu > 0");
+
+    let z = float_normal::f64(rng, 0.0, 1.0);
+    // The distribution converges to the standard normal as `nu` grows.
+    if nu.is_infinite() {
+        return z;
+    }
+
+    z / FloatMathExt::sqrt(f64_chi_squared(rng, nu) / nu)
+}
+
+pub(super) fn f32_beta(rng: &mut impl BaseRng, alpha: f32, beta: f32) -> f32 {
+    f64_beta(rng, alpha as f64, beta as f64) as f32
+}
+
+pub(super) fn f64_beta(rng: &mut impl BaseRng, alpha: f64, beta: f64) -> f64 {
+    assert!(
+        alpha > 0.0 && beta > 0.0,
+        "Beta distribution requires alpha > 0 and beta > 0"
+    );
+
+    beta_impl(rng, alpha, beta)
+}
+
+pub(super) fn f64_pert(rng: &mut impl BaseRng, min: f64, mode: f64, max: f64, lambda: f64) -> f64 {
+    assert!(
+        min <= mode && mode <= max && min < max,
+        "PERT distribution requires min <= mode <= max and min < max"
+    );
+    assert!(lambda > 0.0, "PERT distribution requires lambda > 0");
+
+    // https://en.wikipedia.org/wiki/PERT_distribution
+    let range = max - min;
+    let alpha = 1.0 + lambda * (mode - min) / range;
+    let beta = 1.0 + lambda * (max - mode) / range;
+
+    min + beta_impl(rng, alpha, beta) * range
+}
+
+pub(super) fn f64_beta_mean(rng: &mut impl BaseRng, mean: f64, concentration: f64) -> f64 {
+    assert!(
+        mean > 0.0 && mean < 1.0,
+        "Beta distribution requires 0 < mean < 1"
+    );
+    assert!(
+        concentration > 0.0,
+        "Beta distribution requires concentration > 0"
+    );
+
+    beta_impl(rng, mean * concentration, (1.0 - mean) * concentration)
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn dirichlet(rng: &mut impl BaseRng, alphas: &[f64]) -> Vec<f64> {
+    debug_assert!(
+        alphas.iter().all(|&alpha| alpha > 0.0),
+        "Dirichlet distribution requires all alphas > 0"
+    );
+
+    // Normalized independent Gamma(alpha_i, 1) variates follow the Dirichlet
+    // distribution.
+    let mut values: Vec<f64> = alphas.iter().map(|&alpha| gamma_impl(rng, alpha)).collect();
+    let sum: f64 = values.iter().sum();
+
+    if sum > 0.0 {
+        for value in values.iter_mut() {
+            *value /= sum;
+        }
+    } else if !values.is_empty() {
+        // With very small alphas, all variates may underflow to zero. In the
+        // limit, all of the mass goes to a single component chosen with
+        // probability proportional to its alpha.
+        let total: f64 = alphas.iter().sum();
+        let mut target = rng.f64() * total;
+        let mut chosen = values.len() - 1;
+        for (i, &alpha) in alphas.iter().enumerate() {
+            if target < alpha {
+                chosen = i;
+                break;
+            }
+            target -= alpha;
+        }
+        values[chosen] = 1.0;
+    }
+
+    values
+}
+
+fn beta_impl(rng: &mut impl BaseRng, alpha: f64, beta: f64) -> f64 {
+    // If X ~ Gamma(alpha, 1) and Y ~ Gamma(beta, 1), then X / (X + Y) ~ Beta(alpha, beta).
+    // For small shapes, both variates may underflow to zero, so the ratio is
+    // computed from their logarithms as `1 / (1 + e^(ln Y - ln X))`.
+    let ln_x = ln_gamma_variate(rng, alpha);
+    let ln_y = ln_gamma_variate(rng, beta);
+    let value = if ln_x >= ln_y {
+        1.0 / (1.0 + FloatMathExt::exp(ln_y - ln_x))
+    } else {
+        let e = FloatMathExt::exp(ln_x - ln_y);
+        e / (1.0 + e)
+    };
+    // Clamp in case rounding pushes the ratio slightly out of bounds.
+    value.max(0.0).min(1.0)
+}
+
+/// Generate the logarithm of a Gamma(shape, 1) variate without underflowing
+/// for small shapes.
+fn ln_gamma_variate(rng: &mut impl BaseRng, shape: f64) -> f64 {
+    if shape < 1.0 {
+        // The same boost as in `gamma_impl`, but added in log space.
+        let u = rng.f64_open();
+        FloatMathExt::ln(gamma_impl(rng, shape + 1.0)) + FloatMathExt::ln(u) / shape
+    } else {
+        FloatMathExt::ln(gamma_impl(rng, shape))
+    }
+}
+
+fn gamma_impl(rng: &mut impl BaseRng, shape: f64) -> f64 {
+    // Marsaglia and Tsang's method, which works for shape >= 1:
+    // https://dl.acm.org/doi/10.1145/358407.358414
+    if shape < 1.0 {
+        // Gamma(shape) can be obtained from Gamma(shape + 1) by multiplying it
+        // with U^(1 / shape) where U is uniform in (0, 1).
+        let u = rng.f64_open();
+        return gamma_impl(rng, shape + 1.0) * FloatMathExt::powf(u, 1.0 / shape);
+    }
+
+    let d = shape - 1.0 / 3.0;
+    let c = 1.0 / FloatMathExt::sqrt(9.0 * d);
+
+    loop {
+        let x = float_normal::f64(rng, 0.0, 1.0);
+        let v = 1.0 + c * x;
+        if v <= 0.0 {
+            continue;
+        }
+
+        let v = v * v * v;
+        let u = rng.f64_open();
+        let x2 = x * x;
+
+        // Cheap squeeze check first, then the exact one.
+        if u < 1.0 - 0.0331 * x2 * x2
+            || FloatMathExt::ln(u) < 0.5 * x2 + d * (1.0 - v + FloatMathExt::ln(v))
+        {
+            return d * v;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn gamma_has_expected_mean_and_variance() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(shape, scale) in [(0.5, 2.0), (1.0, 1.0), (3.0, 0.5), (20.0, 3.0)].iter() {
+            let total = 100_000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..total {
+                let value = f64_gamma(&mut rng, shape, scale);
+                assert!(value >= 0.0);
+                sum += value;
+                sum_sq += value * value;
+            }
+            let mean = sum / total as f64;
+            let variance = sum_sq / total as f64 - mean * mean;
+
+            let expected_mean = shape * scale;
+            let expected_variance = shape * scale * scale;
+            assert!(
+                (mean - expected_mean).abs() < 0.02 * expected_mean,
+                "mean should be ~{}, but is {}",
+                expected_mean,
+                mean
+            );
+            assert!(
+                (variance - expected_variance).abs() < 0.05 * expected_variance,
+                "variance should be ~{}, but is {}",
+                expected_variance,
+                variance
+            );
+        }
+    }
+
+    #[test]
+    fn chi_squared_has_expected_mean_and_variance() {
+        let mut rng = Rng::with_seed(42);
+
+        for &k in [0.5, 3.0, 7.5].iter() {
+            let total = 100_000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..total {
+                let value = f64_chi_squared(&mut rng, k);
+                assert!(value >= 0.0);
+                sum += value;
+                sum_sq += value * value;
+            }
+            let mean = sum / total as f64;
+            let variance = sum_sq / total as f64 - mean * mean;
+
+            assert!(
+                (mean - k).abs() < 0.02 * k,
+                "mean should be ~{}, but is {}",
+                k,
+                mean
+            );
+            assert!(
+                (variance - 2.0 * k).abs() < 0.05 * 2.0 * k,
+                "variance should be ~{}, but is {}",
+                2.0 * k,
+                variance
+            );
+        }
+    }
+
+    #[test]
+    fn students_t_is_symmetric_with_expected_variance() {
+        let mut rng = Rng::with_seed(42);
+
+        for &nu in [10.0, 1e9, f64::INFINITY].iter() {
+            let total = 100_000;
+            let mut sum_sq = 0.0;
+            let mut below_zero = 0;
+            for _ in 0..total {
+                let value = f64_students_t(&mut rng, nu);
+                sum_sq += value * value;
+                if value < 0.0 {
+                    below_zero += 1;
+                }
+            }
+
+            let below_zero = below_zero as f64 / total as f64;
+            assert!((below_zero - 0.5).abs() < 0.01, "{}", below_zero);
+
+            let variance = sum_sq / total as f64;
+            let expected = if nu.is_infinite() {
+                1.0
+            } else {
+                nu / (nu - 2.0)
+            };
+            assert!(
+                (variance - expected).abs() < 0.05 * expected,
+                "variance should be ~{}, but is {}",
+                expected,
+                variance
+            );
+        }
+    }
+
+    #[test]
+    fn beta_has_expected_mean() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(alpha, beta) in [(0.5, 0.5), (2.0, 5.0), (10.0, 1.0)].iter() {
+            let total = 10000;
+            let mut sum = 0.0;
+            for _ in 0..total {
+                let value = f64_beta(&mut rng, alpha, beta);
+                assert!((0.0..=1.0).contains(&value));
+                sum += value;
+            }
+            let mean = sum / total as f64;
+
+            let expected = alpha / (alpha + beta);
+            assert!(
+                (mean - expected).abs() < 0.01,
+                "mean should be ~{}, but is {}",
+                expected,
+                mean
+            );
+        }
+    }
+
+    #[test]
+    fn beta_with_tiny_shapes_is_symmetric() {
+        let mut rng = Rng::with_seed(42);
+
+        // Both gamma variates underflow to zero for most draws, but the mass
+        // must still split evenly between the two ends.
+        let total = 100_000;
+        let mut sum = 0.0;
+        let mut below_half = 0;
+        for _ in 0..total {
+            let value = f64_beta(&mut rng, 0.001, 0.001);
+            assert!((0.0..=1.0).contains(&value));
+            sum += value;
+            if value < 0.5 {
+                below_half += 1;
+            }
+        }
+
+        let mean = sum / total as f64;
+        assert!(
+            (mean - 0.5).abs() < 0.01,
+            "mean should be ~0.5, but is {}",
+            mean
+        );
+        let below_half = below_half as f64 / total as f64;
+        assert!(
+            (below_half - 0.5).abs() < 0.01,
+            "{} of the values are below 0.5",
+            below_half
+        );
+    }
+
+    #[test]
+    fn pert_in_bounds_and_concentrates_near_mode() {
+        let mut rng = Rng::with_seed(42);
+
+        let (min, mode, max) = (2.0, 3.0, 10.0);
+        let mean_distance_from_mode = |rng: &mut Rng, lambda: f64| {
+            let total = 10000;
+            let mut sum = 0.0;
+            for _ in 0..total {
+                let value = f64_pert(rng, min, mode, max, lambda);
+                assert!((min..=max).contains(&value));
+                sum += (value - mode).abs();
+            }
+            sum / total as f64
+        };
+
+        let wide = mean_distance_from_mode(&mut rng, 4.0);
+        let narrow = mean_distance_from_mode(&mut rng, 40.0);
+        assert!(
+            narrow < wide,
+            "larger lambda should concentrate values near the mode ({} >= {})",
+            narrow,
+            wide
+        );
+    }
+
+    #[test]
+    fn beta_mean_has_expected_mean_and_variance() {
+        let mut rng = Rng::with_seed(42);
+
+        let mean = 0.3;
+        let stats = |rng: &mut Rng, concentration: f64| {
+            let total = 10000;
+            let mut sum = 0.0;
+            let mut sum_sq = 0.0;
+            for _ in 0..total {
+                let value = f64_beta_mean(rng, mean, concentration);
+                sum += value;
+                sum_sq += value * value;
+            }
+            let sample_mean = sum / total as f64;
+            let variance = sum_sq / total as f64 - sample_mean * sample_mean;
+            (sample_mean, variance)
+        };
+
+        let (low_mean, low_variance) = stats(&mut rng, 2.0);
+        let (high_mean, high_variance) = stats(&mut rng, 50.0);
+        assert!((low_mean - mean).abs() < 0.01, "mean is {}", low_mean);
+        assert!((high_mean - mean).abs() < 0.01, "mean is {}", high_mean);
+        assert!(high_variance < low_variance);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn dirichlet_sums_to_one_with_expected_means() {
+        let mut rng = Rng::with_seed(42);
+
+        let alphas = [0.5, 1.0, 2.0, 4.5];
+        let alpha_sum: f64 = alphas.iter().sum();
+        let total = 20_000;
+        let mut sums = [0.0; 4];
+        for _ in 0..total {
+            let values = dirichlet(&mut rng, &alphas);
+            assert_eq!(values.len(), alphas.len());
+            assert!(values.iter().all(|&value| (0.0..=1.0).contains(&value)));
+            assert!((values.iter().sum::<f64>() - 1.0).abs() < 1e-12);
+            for (sum, value) in sums.iter_mut().zip(values) {
+                *sum += value;
+            }
+        }
+
+        for (&sum, &alpha) in sums.iter().zip(alphas.iter()) {
+            let mean = sum / total as f64;
+            let expected = alpha / alpha_sum;
+            assert!(
+                (mean - expected).abs() < 0.01,
+                "component mean is {}, expected ~{}",
+                mean,
+                expected
+            );
+        }
+
+        // All variates underflow to zero, the mass goes to one component.
+        let values = dirichlet(&mut rng, &[1e-300, 1e-300]);
+        assert_eq!(values.iter().sum::<f64>(), 1.0);
+    }
+}
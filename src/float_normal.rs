@@ -1,5 +1,7 @@
-use core::ops::{Add, Mul, Neg};
+use core::ops::{Add, Div, Mul, Neg, Sub};
 
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::ziggurat_tables;
 use crate::BaseRng;
 
 #[cfg(any(feature = "std", feature = "libm"))]
@@ -12,6 +14,212 @@ pub(super) fn f64(rng: &mut impl BaseRng, mu: f64, sigma: f64) -> f64 {
     float_normal_impl(rng, mu, sigma)
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_pair(rng: &mut impl BaseRng, mu: f64, sigma: f64) -> (f64, f64) {
+    float_normal_pair_impl(rng, mu, sigma)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_ziggurat(rng: &mut impl BaseRng, mu: f64, sigma: f64) -> f64 {
+    use ziggurat_tables::{R, RATIO, X};
+
+    // https://www.doornik.com/research/ziggurat.pdf
+    loop {
+        // The low 7 bits select the layer and the high 53 bits give a uniform
+        // number in [-1, 1).
+        let bits = rng.u64();
+        let i = (bits & 0x7f) as usize;
+        let u = (bits >> 11) as f64 * (1.0 / (1u64 << 52) as f64) - 1.0;
+
+        // In the vast majority of cases, the point lies inside the next layer
+        // and no transcendental functions are needed.
+        if u.abs() < RATIO[i] {
+            return u * X[i] * sigma + mu;
+        }
+
+        if i == 0 {
+            // The base layer sticks out into the tail, which is sampled with
+            // Marsaglia's method.
+            let x = loop {
+                let x = -FloatMathExt::ln(rng.f64_open()) / R;
+                let y = -FloatMathExt::ln(rng.f64_open());
+                if y + y > x * x {
+                    break x;
+                }
+            };
+            let z = if u < 0.0 { -(R + x) } else { R + x };
+            return z * sigma + mu;
+        }
+
+        // The point lies in the wedge between this layer and the next one, so
+        // compare it with the density itself.
+        let x = u * X[i];
+        let f0 = FloatMathExt::exp(-0.5 * (X[i] * X[i] - x * x));
+        let f1 = FloatMathExt::exp(-0.5 * (X[i + 1] * X[i + 1] - x * x));
+        if f1 + rng.f64() * (f0 - f1) < 1.0 {
+            return x * sigma + mu;
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn fill_f64(rng: &mut impl BaseRng, buf: &mut [f64], mu: f64, sigma: f64) {
+    let mut chunks = buf.chunks_exact_mut(2);
+    for pair in &mut chunks {
+        let (a, b) = float_normal_pair_impl(rng, mu, sigma);
+        pair[0] = a;
+        pair[1] = b;
+    }
+    for x in chunks.into_remainder() {
+        *x = float_normal_impl(rng, mu, sigma);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f32_lognormal(rng: &mut impl BaseRng, mu: f32, sigma: f32) -> f32 {
+    float_lognormal_impl(rng, mu, sigma)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_lognormal(rng: &mut impl BaseRng, mu: f64, sigma: f64) -> f64 {
+    float_lognormal_impl(rng, mu, sigma)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_reflected(
+    rng: &mut impl BaseRng,
+    mu: f64,
+    sigma: f64,
+    low: f64,
+    high: f64,
+) -> f64 {
+    assert!(low < high, "reflecting bounds require low < high");
+
+    let value = float_normal_impl(rng, mu, sigma);
+
+    // Reflecting repeatedly off both bounds is periodic with a period of twice
+    // the width, so a single remainder is enough instead of bouncing in a loop.
+    let width = high - low;
+    let period = 2.0 * width;
+    let mut offset = (value - low) % period;
+    if offset < 0.0 {
+        offset += period;
+    }
+    if offset > width {
+        offset = period - offset;
+    }
+
+    low + offset
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_skew_normal(
+    rng: &mut impl BaseRng,
+    location: f64,
+    scale: f64,
+    alpha: f64,
+) -> f64 {
+    // If `u0` and `v` are independent standard normals, `delta * |u0| +
+    // sqrt(1 - delta^2) * v` is skew-normal with shape `alpha`.
+    let delta = alpha / FloatMathExt::sqrt(1.0 + alpha * alpha);
+    let u0: f64 = float_normal_impl(rng, 0.0, 1.0);
+    let v = float_normal_impl(rng, 0.0, 1.0);
+    let z = delta * u0.abs() + FloatMathExt::sqrt(1.0 - delta * delta) * v;
+
+    location + scale * z
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_truncated(
+    rng: &mut impl BaseRng,
+    mu: f64,
+    sigma: f64,
+    low: f64,
+    high: f64,
+) -> f64 {
+    debug_assert!(low < high, "truncation bounds require low < high");
+    debug_assert!(sigma > 0.0, "truncated normal requires sigma > 0");
+
+    // Sample the standard normal truncated to the standardized bounds. Tails
+    // are mirrored so that only the right one needs to be handled.
+    let a = (low - mu) / sigma;
+    let b = (high - mu) / sigma;
+    let z = if a >= 0.0 {
+        truncated_tail(rng, a, b)
+    } else if b <= 0.0 {
+        -truncated_tail(rng, -b, -a)
+    } else if b - a >= SQRT_TAU {
+        // At least about half of the mass is within the bounds, so plain
+        // rejection is efficient.
+        loop {
+            let z = float_normal_impl(rng, 0.0, 1.0);
+            if a <= z && z <= b {
+                break z;
+            }
+        }
+    } else {
+        // Narrow interval around the mean, use a uniform proposal.
+        loop {
+            let z = a + (b - a) * rng.f64();
+            if rng.f64() <= FloatMathExt::exp(-0.5 * z * z) {
+                break z;
+            }
+        }
+    };
+
+    // Rounding may push the value slightly out of the bounds.
+    (mu + sigma * z).max(low).min(high)
+}
+
+/// Sample the standard normal truncated to `[a, b]` with `0 <= a < b`.
+///
+/// This is Robert's algorithm, see "Simulation of truncated normal variables".
+#[cfg(any(feature = "std", feature = "libm"))]
+fn truncated_tail(rng: &mut impl BaseRng, a: f64, b: f64) -> f64 {
+    let root = FloatMathExt::sqrt(a * a + 4.0);
+    // The rate of the exponential proposal which maximizes the acceptance.
+    let lambda = 0.5 * (a + root);
+
+    // A uniform proposal is more efficient if the interval is narrow.
+    let uniform_max_width = 2.0 * FloatMathExt::sqrt(core::f64::consts::E) / (a + root)
+        * FloatMathExt::exp(0.25 * (a * a - a * root));
+    if b - a < uniform_max_width {
+        loop {
+            let z = a + (b - a) * rng.f64();
+            if rng.f64() <= FloatMathExt::exp(0.5 * (a * a - z * z)) {
+                return z;
+            }
+        }
+    }
+
+    loop {
+        let z = a - FloatMathExt::ln(rng.f64_open()) / lambda;
+        if z > b {
+            continue;
+        }
+        let d = z - lambda;
+        if rng.f64() <= FloatMathExt::exp(-0.5 * d * d) {
+            return z;
+        }
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_correlated_pair(rng: &mut impl BaseRng, rho: f64) -> (f64, f64) {
+    assert!(
+        (-1.0..=1.0).contains(&rho),
+        "correlation must be in [-1, 1]"
+    );
+
+    // Gaussian copula: correlate two standard normals and map them through
+    // the normal CDF, which makes them uniform while preserving their ranks.
+    let z1 = float_normal_impl(rng, 0.0, 1.0);
+    let z2 = float_normal_impl(rng, 0.0, 1.0);
+    let y = rho * z1 + FloatMathExt::sqrt(1.0 - rho * rho) * z2;
+
+    (normal_cdf(z1), normal_cdf(y))
+}
+
 pub(super) fn f32_approx(rng: &mut impl BaseRng, mu: f32, sigma: f32) -> f32 {
     float_normal_approx_impl(rng, mu, sigma)
 }
@@ -20,8 +228,15 @@ pub(super) fn f64_approx(rng: &mut impl BaseRng, mu: f64, sigma: f64) -> f64 {
     float_normal_approx_impl(rng, mu, sigma)
 }
 
-trait FloatExt:
-    Add<Self, Output = Self> + Mul<Self, Output = Self> + Neg<Output = Self> + PartialOrd<Self> + Sized
+pub(super) trait FloatExt:
+    Add<Self, Output = Self>
+    + Sub<Self, Output = Self>
+    + Mul<Self, Output = Self>
+    + Div<Self, Output = Self>
+    + Neg<Output = Self>
+    + PartialOrd<Self>
+    + Copy
+    + Sized
 {
     const EPSILON: Self;
 
@@ -31,12 +246,25 @@ trait FloatExt:
 }
 
 #[cfg(any(feature = "std", feature = "libm"))]
-trait FloatMathExt: FloatExt {
+pub(super) trait FloatMathExt: FloatExt {
     const TAU: Self;
+    const INFINITY: Self;
+    const MIN_POSITIVE: Self;
 
     fn ln(self) -> Self;
+    /// `ln(1 + self)`, accurate even if `self` is close to zero.
+    fn ln_1p(self) -> Self;
+    fn exp(self) -> Self;
+    fn exp_m1(self) -> Self;
     fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn sin(self) -> Self;
     fn cos(self) -> Self;
+    fn tan(self) -> Self;
+    fn acos(self) -> Self;
+    /// The complementary error function.
+    fn erfc(self) -> Self;
 }
 
 macro_rules! impl_float_ext {
@@ -62,47 +290,157 @@ macro_rules! impl_float_math_ext {
         #[cfg(all(feature = "std", not(feature = "libm")))]
         impl FloatMathExt for $float {
             const TAU: Self = $tau;
+            const INFINITY: Self = $float::INFINITY;
+            const MIN_POSITIVE: Self = $float::MIN_POSITIVE;
 
             #[inline]
             fn ln(self) -> Self {
                 $float::ln(self)
             }
             #[inline]
+            fn ln_1p(self) -> Self {
+                $float::ln_1p(self)
+            }
+            #[inline]
+            fn exp(self) -> Self {
+                $float::exp(self)
+            }
+            #[inline]
+            fn exp_m1(self) -> Self {
+                $float::exp_m1(self)
+            }
+            #[inline]
             fn sqrt(self) -> Self {
                 $float::sqrt(self)
             }
             #[inline]
+            fn cbrt(self) -> Self {
+                $float::cbrt(self)
+            }
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                $float::powf(self, n)
+            }
+            #[inline]
+            fn sin(self) -> Self {
+                $float::sin(self)
+            }
+            #[inline]
             fn cos(self) -> Self {
                 $float::cos(self)
             }
+            #[inline]
+            fn tan(self) -> Self {
+                $float::tan(self)
+            }
+            #[inline]
+            fn acos(self) -> Self {
+                $float::acos(self)
+            }
+            #[inline]
+            fn erfc(self) -> Self {
+                erfc_approx(self as f64) as $float
+            }
         }
 
         #[cfg(feature = "libm")]
         impl FloatMathExt for $float {
             const TAU: Self = $tau;
+            const INFINITY: Self = $float::INFINITY;
+            const MIN_POSITIVE: Self = $float::MIN_POSITIVE;
 
             #[inline]
             fn ln(self) -> Self {
                 libm_dep::Libm::<$float>::log(self)
             }
             #[inline]
+            fn ln_1p(self) -> Self {
+                libm_dep::Libm::<$float>::log1p(self)
+            }
+            #[inline]
+            fn exp(self) -> Self {
+                libm_dep::Libm::<$float>::exp(self)
+            }
+            #[inline]
+            fn exp_m1(self) -> Self {
+                libm_dep::Libm::<$float>::expm1(self)
+            }
+            #[inline]
             fn sqrt(self) -> Self {
                 libm_dep::Libm::<$float>::sqrt(self)
             }
             #[inline]
+            fn cbrt(self) -> Self {
+                libm_dep::Libm::<$float>::cbrt(self)
+            }
+            #[inline]
+            fn powf(self, n: Self) -> Self {
+                libm_dep::Libm::<$float>::pow(self, n)
+            }
+            #[inline]
+            fn sin(self) -> Self {
+                libm_dep::Libm::<$float>::sin(self)
+            }
+            #[inline]
             fn cos(self) -> Self {
                 libm_dep::Libm::<$float>::cos(self)
             }
+            #[inline]
+            fn tan(self) -> Self {
+                libm_dep::Libm::<$float>::tan(self)
+            }
+            #[inline]
+            fn acos(self) -> Self {
+                libm_dep::Libm::<$float>::acos(self)
+            }
+            #[inline]
+            fn erfc(self) -> Self {
+                libm_dep::Libm::<$float>::erfc(self)
+            }
         }
     };
 }
 
+/// The complementary error function.
+#[cfg(all(feature = "std", not(feature = "libm")))]
+fn erfc_approx(x: f64) -> f64 {
+    // `std` doesn't provide the error function. This is the Chebyshev
+    // approximation from Numerical Recipes with a fractional error below
+    // 1.2e-7 everywhere.
+    let z = x.abs();
+    let t = 1.0 / (1.0 + 0.5 * z);
+    let poly = -z * z - 1.265_512_23
+        + t * (1.000_023_68
+            + t * (0.374_091_96
+                + t * (0.096_784_18
+                    + t * (-0.186_288_06
+                        + t * (0.278_868_07
+                            + t * (-1.135_203_98
+                                + t * (1.488_515_87 + t * (-0.822_152_23 + t * 0.170_872_77))))))));
+    let ans = t * poly.exp();
+
+    if x >= 0.0 {
+        ans
+    } else {
+        2.0 - ans
+    }
+}
+
+/// The cumulative distribution function of the standard normal distribution.
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(crate) fn normal_cdf(x: f64) -> f64 {
+    0.5 * FloatMathExt::erfc(-x * core::f64::consts::FRAC_1_SQRT_2)
+}
+
 // TAU constant was stabilized in Rust 1.47. Our current MSRV is 1.43.
 #[cfg(any(feature = "std", feature = "libm"))]
 #[allow(clippy::excessive_precision)]
 const F32_TAU: f32 = 6.28318530717958647692528676655900577_f32;
 #[cfg(any(feature = "std", feature = "libm"))]
 #[allow(clippy::excessive_precision)]
+const SQRT_TAU: f64 = 2.50662827463100050241576528481104525_f64;
+#[cfg(any(feature = "std", feature = "libm"))]
+#[allow(clippy::excessive_precision)]
 const F64_TAU: f64 = 6.28318530717958647692528676655900577_f64;
 
 impl_float_ext!(f32);
@@ -126,6 +464,37 @@ fn float_normal_impl<T: FloatMathExt>(rng: &mut impl BaseRng, mu: T, sigma: T) -
     mag * (T::TAU * u2).cos() + mu
 }
 
+/// Generate two independent normal values from a single Box-Muller draw.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn float_normal_pair_impl<T: FloatMathExt>(rng: &mut impl BaseRng, mu: T, sigma: T) -> (T, T) {
+    let u1 = loop {
+        let u1 = T::gen(rng);
+
+        if u1 > T::EPSILON {
+            break u1;
+        }
+    };
+
+    // The cosine and sine terms of the same angle are independent, so the
+    // second value comes almost for free.
+    let u2 = T::gen(rng);
+    let mag = sigma * (-T::from_f64(2.0) * u1.ln()).sqrt();
+    let theta = T::TAU * u2;
+    (mag * theta.cos() + mu, mag * theta.sin() + mu)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn float_lognormal_impl<T: FloatMathExt>(rng: &mut impl BaseRng, mu: T, sigma: T) -> T {
+    let value = float_normal_impl(rng, mu, sigma).exp();
+
+    // Very negative normal values underflow to zero.
+    if value > T::MIN_POSITIVE {
+        value
+    } else {
+        T::MIN_POSITIVE
+    }
+}
+
 fn float_normal_approx_impl<T: FloatExt>(rng: &mut impl BaseRng, mu: T, sigma: T) -> T {
     // http://marc-b-reynolds.github.io/distribution/2021/03/18/CheapGaussianApprox.html
     let u = rng.u128();
@@ -214,8 +583,241 @@ mod tests {
         normal_distribution_test(float_normal_impl);
     }
 
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn normal_ziggurat_is_actually_normal() {
+        normal_distribution_test(|rng, mu, sigma| {
+            f64_ziggurat(rng, f64::from(mu), f64::from(sigma)) as f32
+        });
+
+        // Check the moments and the tail, which is sampled separately beyond
+        // ~3.44 sigma.
+        let mut rng = Rng::with_seed(42);
+        let total = 1_000_000;
+        let mut sum = 0.0;
+        let mut sum_sq = 0.0;
+        let mut in_tail = 0;
+        for _ in 0..total {
+            let value = f64_ziggurat(&mut rng, 0.0, 1.0);
+            sum += value;
+            sum_sq += value * value;
+            if value.abs() > 3.5 {
+                in_tail += 1;
+            }
+        }
+        let mean = sum / total as f64;
+        let variance = sum_sq / total as f64 - mean * mean;
+        assert!(mean.abs() < 0.005, "mean is {}", mean);
+        assert!((variance - 1.0).abs() < 0.01, "variance is {}", variance);
+        // P(|Z| > 3.5) is ~0.0465%, so ~465 values are expected.
+        assert!(
+            (380..=550).contains(&in_tail),
+            "{} values beyond 3.5 sigma",
+            in_tail
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn normal_pair_is_normal_and_uncorrelated() {
+        normal_distribution_test(|rng, mu, sigma| float_normal_pair_impl(rng, mu, sigma).0);
+        normal_distribution_test(|rng, mu, sigma| float_normal_pair_impl(rng, mu, sigma).1);
+
+        let mut rng = Rng::with_seed(42);
+        let total = 100_000;
+        let mut sum_xy = 0.0;
+        for _ in 0..total {
+            let (x, y) = f64_pair(&mut rng, 0.0, 1.0);
+            sum_xy += x * y;
+        }
+        // Both values have zero mean and unit variance, so the mean of the
+        // product is their correlation.
+        let correlation = sum_xy / total as f64;
+        assert!(correlation.abs() < 0.02, "correlation is {}", correlation);
+    }
+
     #[test]
     fn normal_approx_is_actually_normal() {
         normal_distribution_test(float_normal_approx_impl);
     }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn lognormal_median_is_exp_mu() {
+        let mut rng = Rng::with_seed(42);
+
+        // The sample median is close to `e^mu` if about half of the values
+        // are below it.
+        let (mu, sigma) = (1.5, 0.8);
+        let median = FloatMathExt::exp(mu);
+        let total = 50_000;
+        let mut below_median = 0;
+        for _ in 0..total {
+            let value = float_lognormal_impl(&mut rng, mu, sigma);
+            assert!(value > 0.0);
+            if value < median {
+                below_median += 1;
+            }
+        }
+
+        let below_median = below_median as f64 / total as f64 * 100.0;
+        assert!(
+            (49.0..=51.0).contains(&below_median),
+            "value below e^mu should be sampled ~50%, but is {}%",
+            below_median
+        );
+
+        assert!(float_lognormal_impl(&mut rng, -1000.0_f32, 1.0) > 0.0);
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn normal_reflected_in_bounds_and_symmetric() {
+        let mut rng = Rng::with_seed(42);
+
+        let (low, high) = (-1.0, 1.0);
+        let total = 10000;
+        let mut below_center = 0;
+        for _ in 0..total {
+            let value = f64_reflected(&mut rng, 0.0, 3.0, low, high);
+            assert!((low..=high).contains(&value));
+            if value < 0.0 {
+                below_center += 1;
+            }
+        }
+
+        let below_center = below_center as f64 / total as f64 * 100.0;
+        assert!(
+            (48.0..=52.0).contains(&below_center),
+            "value below center should be sampled ~50%, but is {}%",
+            below_center
+        );
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn skew_normal_without_skew_is_normal() {
+        normal_distribution_test(|rng, mu, sigma| {
+            f64_skew_normal(rng, mu as f64, sigma as f64, 0.0) as f32
+        });
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn skew_normal_is_skewed() {
+        let mut rng = Rng::with_seed(42);
+
+        for &alpha in [4.0, -4.0].iter() {
+            let total = 100_000;
+            let mut sum = 0.0;
+            for _ in 0..total {
+                sum += f64_skew_normal(&mut rng, 1.0, 2.0, alpha);
+            }
+
+            // The mean is `location + scale * delta * sqrt(2 / pi)`.
+            let delta = alpha / FloatMathExt::sqrt(1.0 + alpha * alpha);
+            let expected = 1.0 + 2.0 * delta * FloatMathExt::sqrt(2.0 / core::f64::consts::PI);
+            let mean = sum / total as f64;
+            assert!(
+                (mean - expected).abs() < 0.02,
+                "mean should be ~{}, but is {}",
+                expected,
+                mean
+            );
+
+            // With positive skew, the mean exceeds the median, so more than
+            // half of the values are below the mean.
+            let below_mean = (0..total)
+                .filter(|_| f64_skew_normal(&mut rng, 1.0, 2.0, alpha) < mean)
+                .count();
+            let below_mean = below_mean as f64 / total as f64;
+            if alpha > 0.0 {
+                assert!(below_mean > 0.52, "{} below mean", below_mean);
+            } else {
+                assert!(below_mean < 0.48, "{} below mean", below_mean);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn truncated_in_bounds_with_expected_mean() {
+        let mut rng = Rng::with_seed(42);
+
+        // Probability density and upper tail probability of the standard
+        // normal distribution.
+        let pdf = |x: f64| FloatMathExt::exp(-0.5 * x * x) / SQRT_TAU;
+        let tail = |x: f64| 0.5 * FloatMathExt::erfc(x * core::f64::consts::FRAC_1_SQRT_2);
+
+        for &(mu, sigma, low, high) in [
+            (0.0, 1.0, -1.0, 2.0),
+            (0.0, 1.0, -0.2, 0.3),
+            (5.0, 2.0, 8.0, 8.5),
+            (0.0, 1.0, 3.0, 5.0),
+            (1.0, 0.5, -10.0, -2.0),
+            (0.0, 1.0, 8.0, f64::INFINITY),
+            (0.0, 1.0, -1.0, 10.0),
+        ]
+        .iter()
+        {
+            let total = 50_000;
+            let mut sum = 0.0;
+            for _ in 0..total {
+                let value = f64_truncated(&mut rng, mu, sigma, low, high);
+                assert!((low..=high).contains(&value));
+                sum += value;
+            }
+
+            let (a, b) = ((low - mu) / sigma, (high - mu) / sigma);
+            let expected = if a > 0.0 {
+                mu + sigma * (pdf(a) - pdf(b)) / (tail(a) - tail(b))
+            } else {
+                // Use the mirrored tail for precision on the left side.
+                mu + sigma * (pdf(a) - pdf(b)) / (tail(-b) - tail(-a))
+            };
+            let mean = sum / total as f64;
+            assert!(
+                (mean - expected).abs() < 0.01 * sigma,
+                "mean should be ~{}, but is {} for {:?}",
+                expected,
+                mean,
+                (mu, sigma, low, high)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn correlated_pair_has_expected_correlation() {
+        let mut rng = Rng::with_seed(42);
+
+        let correlation = |rng: &mut Rng, rho: f64| {
+            let total = 20000;
+            let (mut sx, mut sy, mut sxx, mut syy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+            for _ in 0..total {
+                let (x, y) = f64_correlated_pair(rng, rho);
+                assert!((0.0..=1.0).contains(&x) && (0.0..=1.0).contains(&y));
+                if rho == 1.0 {
+                    assert_eq!(x, y);
+                }
+                sx += x;
+                sy += y;
+                sxx += x * x;
+                syy += y * y;
+                sxy += x * y;
+            }
+            let n = total as f64;
+            let cov = sxy / n - sx / n * sy / n;
+            let var_x = sxx / n - sx / n * sx / n;
+            let var_y = syy / n - sy / n * sy / n;
+            cov / FloatMathExt::sqrt(var_x * var_y)
+        };
+
+        // For the Gaussian copula, the correlation of the uniforms is
+        // 6 / pi * asin(rho / 2).
+        assert!(correlation(&mut rng, 0.0).abs() < 0.03);
+        assert!((correlation(&mut rng, 0.7) - 0.683).abs() < 0.03);
+        assert!((correlation(&mut rng, -0.7) + 0.683).abs() < 0.03);
+        assert!((correlation(&mut rng, 1.0) - 1.0).abs() < 1e-9);
+    }
 }
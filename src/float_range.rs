@@ -1,21 +1,254 @@
+use core::fmt;
 use core::ops::{Add, Bound, Div, Mul, Neg, RangeBounds, Sub};
 
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::float_normal::FloatMathExt;
 use crate::BaseRng;
 
 pub(super) fn f32(rng: &mut impl BaseRng, range: impl RangeBounds<f32>) -> f32 {
-    float_range_impl(rng, range)
+    float_range_impl(rng, range).unwrap_or_else(|err| panic!("{}", err))
 }
 
 pub(super) fn f64(rng: &mut impl BaseRng, range: impl RangeBounds<f64>) -> f64 {
+    float_range_impl(rng, range).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub(super) fn fill_f64(rng: &mut impl BaseRng, buf: &mut [f64], range: impl RangeBounds<f64>) {
+    fill_range_impl(rng, buf, range).unwrap_or_else(|err| panic!("{}", err))
+}
+
+pub(super) fn try_f32(
+    rng: &mut impl BaseRng,
+    range: impl RangeBounds<f32>,
+) -> Result<f32, RangeError> {
+    float_range_impl(rng, range)
+}
+
+pub(super) fn try_f64(
+    rng: &mut impl BaseRng,
+    range: impl RangeBounds<f64>,
+) -> Result<f64, RangeError> {
     float_range_impl(rng, range)
 }
 
+pub(super) fn f64_inclusive(rng: &mut impl BaseRng, low: f64, high: f64) -> f64 {
+    assert!(low <= high, "range must satisfy low <= high and not be NaN");
+
+    // Interpolate instead of computing `low + t * (high - low)`, so that the
+    // bounds are hit exactly for `t` being 0 and 1.
+    let t = closed_unit(rng.u64());
+    let x = (1.0 - t) * low + t * high;
+    // Clamp in case rounding pushes the value slightly out of bounds.
+    x.max(low).min(high)
+}
+
+/// Map random bits to one of 2^53 equally spaced floats in `[0, 1]`, both
+/// bounds included.
+fn closed_unit(bits: u64) -> f64 {
+    const MAX: u64 = (1 << 53) - 1;
+    (bits >> 11) as f64 / MAX as f64
+}
+
+pub(super) fn f64_avoiding(
+    rng: &mut impl BaseRng,
+    range: impl RangeBounds<f64>,
+    avoid: f64,
+    radius: f64,
+) -> f64 {
+    assert!(
+        !avoid.is_nan() && !radius.is_nan(),
+        "avoided point and radius must not be NaN"
+    );
+    assert!(radius >= 0.0, "radius must not be negative");
+
+    let start = cloned_bound(range.start_bound());
+    let end = cloned_bound(range.end_bound());
+    let low = match start {
+        Bound::Included(low) | Bound::Excluded(low) => low,
+        Bound::Unbounded => f64::MIN,
+    };
+    let high = match end {
+        Bound::Included(high) | Bound::Excluded(high) => high,
+        Bound::Unbounded => f64::MAX,
+    };
+
+    // The excluded interval is open, so its ends remain part of the two
+    // sub-ranges to the left and to the right of it.
+    let hole_low = avoid - radius;
+    let hole_high = avoid + radius;
+    let left = (
+        start,
+        Bound::Included(if hole_low < high { hole_low } else { high }),
+    );
+    let right = (
+        Bound::Included(if hole_high > low { hole_high } else { low }),
+        end,
+    );
+
+    let left_width = if hole_low > low { hole_low - low } else { 0.0 };
+    let right_width = if high > hole_high {
+        high - hole_high
+    } else {
+        0.0
+    };
+    let total = left_width + right_width;
+    assert!(total > 0.0, "excluded interval covers the whole range");
+
+    if rng.f64() * total < left_width {
+        f64(rng, left)
+    } else {
+        f64(rng, right)
+    }
+}
+
+pub(super) fn f64_importance(
+    rng: &mut impl BaseRng,
+    low: f64,
+    high: f64,
+    pdf: impl Fn(f64) -> f64,
+) -> (f64, f64) {
+    let x = f64(rng, low..high);
+
+    // The density of the uniform proposal is 1 / (high - low).
+    (x, pdf(x) * (high - low))
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f64_shaped(
+    rng: &mut impl BaseRng,
+    range: impl RangeBounds<f64>,
+    shape: Shape,
+) -> f64 {
+    let (low, high) = range_bounds(&range).unwrap_or_else(|err| panic!("{}", err));
+    if let Shape::Uniform = shape {
+        return f64(rng, range);
+    }
+    assert!((high - low).is_finite(), "range must be finite");
+
+    loop {
+        // Map a uniform draw from [0, 1) to a shaped value in [0, 1].
+        let t = match shape {
+            // Handled by the early return above.
+            Shape::Uniform => unreachable!(),
+            // The minimum (maximum) of two uniform draws has the linear density
+            // 2 (1 - t) (2 t).
+            Shape::LeftSkewed => rng.f64().min(rng.f64()),
+            Shape::RightSkewed => rng.f64().max(rng.f64()),
+            // The mean of two uniform draws has the triangular density.
+            Shape::CenterPeaked => 0.5 * (rng.f64() + rng.f64()),
+            Shape::EdgePeaked => {
+                let s = FloatMathExt::sin(0.5 * core::f64::consts::PI * rng.f64());
+                s * s
+            }
+        };
+
+        // Rounding or an excluded bound may put the value outside of the
+        // range. Such values are rare and are simply redrawn.
+        let x = low + t * (high - low);
+        if range.contains(&x) {
+            return x;
+        }
+    }
+}
+
+pub(super) fn f64_triangular(rng: &mut impl BaseRng, low: f64, mode: f64, high: f64) -> f64 {
+    debug_assert!(
+        low <= mode && mode <= high,
+        "triangular distribution requires low <= mode <= high"
+    );
+
+    let width = high - low;
+    let x = triangular_unit(rng, (mode - low) / width);
+    // Clamp in case rounding pushes the value slightly out of bounds.
+    (low + x * width).max(low).min(high)
+}
+
+/// Sample the triangular distribution on `[0, 1]` with the given mode.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn triangular_unit(rng: &mut impl BaseRng, mode: f64) -> f64 {
+    // Inverse transform sampling, with one branch on each side of the mode.
+    let u = rng.f64();
+    if u < mode {
+        FloatMathExt::sqrt(u * mode)
+    } else {
+        1.0 - FloatMathExt::sqrt((1.0 - u) * (1.0 - mode))
+    }
+}
+
+/// Sample the triangular distribution on `[0, 1]` with the given mode.
+#[cfg(not(any(feature = "std", feature = "libm")))]
+fn triangular_unit(rng: &mut impl BaseRng, mode: f64) -> f64 {
+    // Without square roots, mix the minimum and maximum of two uniform draws,
+    // which has the same distribution.
+    let (u, v) = (rng.f64(), rng.f64());
+    let (min, max) = if u < v { (u, v) } else { (v, u) };
+    (1.0 - mode) * min + mode * max
+}
+
+/// The shape of the distribution used by
+/// [`f64_range_shaped`](crate::RngExt::f64_range_shaped).
+#[cfg(any(feature = "std", feature = "libm"))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    /// All values in the range are equally likely.
+    Uniform,
+    /// Values near the lower bound are more likely, with a linearly decreasing
+    /// density.
+    LeftSkewed,
+    /// Values near the upper bound are more likely, with a linearly increasing
+    /// density.
+    RightSkewed,
+    /// Values near the middle of the range are more likely, with a triangular
+    /// density.
+    CenterPeaked,
+    /// Values near either bound are more likely, following the U-shaped
+    /// arcsine distribution.
+    EdgePeaked,
+}
+
+// `Bound::cloned` was stabilized in Rust 1.55. Our current MSRV is 1.43.
+fn cloned_bound<T: Copy>(bound: Bound<&T>) -> Bound<T> {
+    match bound {
+        Bound::Included(&x) => Bound::Included(x),
+        Bound::Excluded(&x) => Bound::Excluded(x),
+        Bound::Unbounded => Bound::Unbounded,
+    }
+}
+
+/// The error returned when a random number can't be generated from a floating
+/// point range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RangeError {
+    /// At least one of the bounds is NaN.
+    Nan,
+    /// The lower bound is greater than the upper bound.
+    Inverted,
+    /// The bounds are equal but at least one of them is excluded, so the range
+    /// contains no values.
+    Empty,
+}
+
+impl fmt::Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RangeError::Nan => f.write_str("range bound is NaN"),
+            RangeError::Inverted => f.write_str("range lower bound is greater than upper bound"),
+            RangeError::Empty => f.write_str("range is empty"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RangeError {}
+
 trait FloatExt:
     Add<Self, Output = Self>
     + Sub<Self, Output = Self>
     + Mul<Self, Output = Self>
     + Div<Self, Output = Self>
     + Neg<Output = Self>
+    + PartialOrd
     + Copy
     + Sized
 {
@@ -24,6 +257,7 @@ trait FloatExt:
     const HALF: Self;
 
     fn is_finite(self) -> bool;
+    fn is_nan(self) -> bool;
     /// Generate a random float in [0, 1) range.
     fn gen_close_01_open(rng: &mut impl BaseRng) -> Self;
     /// Generate a random float in (0, 1] range.
@@ -47,6 +281,10 @@ macro_rules! impl_float_ext {
                 $float::is_finite(self)
             }
             #[inline]
+            fn is_nan(self) -> bool {
+                $float::is_nan(self)
+            }
+            #[inline]
             fn gen_close_01_open(rng: &mut impl BaseRng) -> Self {
                 rng.$float()
             }
@@ -121,7 +359,8 @@ impl Inclusive {
     }
 }
 
-fn float_range_impl<T: FloatExt>(rng: &mut impl BaseRng, range: impl RangeBounds<T>) -> T {
+/// Resolve the bounds of the range and check that it can be sampled from.
+fn range_bounds<T: FloatExt>(range: &impl RangeBounds<T>) -> Result<(T, T), RangeError> {
     let low = match range.start_bound() {
         Bound::Included(&low) | Bound::Excluded(&low) => low,
         Bound::Unbounded => T::MIN,
@@ -132,77 +371,158 @@ fn float_range_impl<T: FloatExt>(rng: &mut impl BaseRng, range: impl RangeBounds
         Bound::Unbounded => T::MAX,
     };
 
+    if low.is_nan() || high.is_nan() {
+        return Err(RangeError::Nan);
+    }
+    if low > high {
+        return Err(RangeError::Inverted);
+    }
+    if low == high {
+        if let (Bound::Excluded(_), _) | (_, Bound::Excluded(_)) =
+            (range.start_bound(), range.end_bound())
+        {
+            return Err(RangeError::Empty);
+        }
+        return Ok((low, high));
+    }
+
+    // Infinite bounds are treated like unbounded ones, so that values are
+    // always finite. Otherwise, the wide range fallback would produce infinity
+    // or NaN.
+    let low = if low < T::MIN { T::MIN } else { low };
+    let high = if high > T::MAX { T::MAX } else { high };
+
+    Ok((low, high))
+}
+
+fn float_range_impl<T: FloatExt>(
+    rng: &mut impl BaseRng,
+    range: impl RangeBounds<T>,
+) -> Result<T, RangeError> {
+    let (low, high) = range_bounds(&range)?;
+    if low == high {
+        return Ok(low);
+    }
+
     let inclusive = Inclusive::from_bounds(range);
+    let scale = range_scale(low, high, &inclusive);
 
+    if scale.is_finite() {
+        Ok(gen_unit::<T>(rng, &inclusive) * scale + low)
+    } else {
+        Ok(float_range_wide(rng, low, high, &inclusive))
+    }
+}
+
+fn fill_range_impl<T: FloatExt>(
+    rng: &mut impl BaseRng,
+    buf: &mut [T],
+    range: impl RangeBounds<T>,
+) -> Result<(), RangeError> {
+    let (low, high) = range_bounds(&range)?;
+    if low == high {
+        for x in buf.iter_mut() {
+            *x = low;
+        }
+        return Ok(());
+    }
+
+    // Resolve the scale and inclusiveness once for the whole buffer instead of
+    // for every element.
+    let inclusive = Inclusive::from_bounds(range);
+    let scale = range_scale(low, high, &inclusive);
+
+    if scale.is_finite() {
+        for x in buf.iter_mut() {
+            *x = gen_unit::<T>(rng, &inclusive) * scale + low;
+        }
+    } else {
+        for x in buf.iter_mut() {
+            *x = float_range_wide(rng, low, high, &inclusive);
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute the scale by which a random number between 0 and 1 is multiplied to
+/// cover the range.
+fn range_scale<T: FloatExt>(low: T, high: T, inclusive: &Inclusive) -> T {
     // Our generator is able to generate floats with one or both sides of the
     // range open. However, it can't generate a float from the range closed on
     // both sides. For this case, we divide the scale by maximum random number
     // which "stretches" the range to include both sides. This is the approach
     // used in rand crate:
     // https://github.com/rust-random/rand/blob/f3dd0b885c4597b9617ca79987a0dd899ab29fcb/src/distributions/uniform.rs#L953
-    let scale = match inclusive {
+    match inclusive {
         Inclusive::None | Inclusive::Left | Inclusive::Right => high - low,
         Inclusive::Both => (high - low) / T::max_rand(),
-    };
+    }
+}
 
-    if scale.is_finite() {
-        // Generate a random number between 0 and 1, where the bounds are
-        // included based on the desired range inclusiveness.
-        let r = match inclusive {
-            Inclusive::None => T::gen_open_01_open(rng),
-            Inclusive::Right => T::gen_open_01_close(rng),
-            Inclusive::Left => T::gen_close_01_open(rng),
-            // Inclusiveness on both sides is achieved by stretching the scale
-            // above.
-            Inclusive::Both => T::gen_close_01_open(rng),
-        };
+/// Generate a random number between 0 and 1, where the bounds are included
+/// based on the desired range inclusiveness.
+fn gen_unit<T: FloatExt>(rng: &mut impl BaseRng, inclusive: &Inclusive) -> T {
+    match inclusive {
+        Inclusive::None => T::gen_open_01_open(rng),
+        Inclusive::Right => T::gen_open_01_close(rng),
+        Inclusive::Left => T::gen_close_01_open(rng),
+        // Inclusiveness on both sides is achieved by stretching the scale in
+        // `range_scale`.
+        Inclusive::Both => T::gen_close_01_open(rng),
+    }
+}
+
+/// Generate a random float in a range whose scale is not finite.
+fn float_range_wide<T: FloatExt>(
+    rng: &mut impl BaseRng,
+    low: T,
+    high: T,
+    inclusive: &Inclusive,
+) -> T {
+    // Scale not being finite means that the range is wider than the float
+    // type can represent (or that at least one side is not finite). In such
+    // case, we need to fall back into the following technique which does a
+    // bit more work but can handle such ranges. Source:
+    // https://medium.com/analytics-vidhya/random-floats-in-any-range-9b40d30b637b
+    let high_half = T::HALF * high;
+    let low_half = T::HALF * low;
+    let mid_point = high_half + low_half;
 
-        r * scale + low
+    // Decide if we generate the value to the right or left from the middle
+    // point. We always want to have a chance that the middle point is
+    // sampled, so we can't use the (0, 1] trick with one-side inclusive
+    // ranges. That is why we stretch those in appropriate cases.
+    let (r, stretch) = if rng.bool() {
+        let stretch = match inclusive {
+            Inclusive::None | Inclusive::Left => false,
+            Inclusive::Right | Inclusive::Both => true,
+        };
+        let r = T::gen_close_01_open(rng);
+        (r, stretch)
     } else {
-        // Scale not being finite means that the range is wider than the float
-        // type can represent (or that at least one side is not finite). In such
-        // case, we need to fall back into the following technique which does a
-        // bit more work but can handle such ranges. Source:
-        // https://medium.com/analytics-vidhya/random-floats-in-any-range-9b40d30b637b
-        let high_half = T::HALF * high;
-        let low_half = T::HALF * low;
-        let mid_point = high_half + low_half;
-
-        // Decide if we generate the value to the right or left from the middle
-        // point. We always want to have a chance that the middle point is
-        // sampled, so we can't use the (0, 1] trick with one-side inclusive
-        // ranges. That is why we stretch those in appropriate cases.
-        let (r, stretch) = if rng.bool() {
-            let stretch = match inclusive {
-                Inclusive::None | Inclusive::Left => false,
-                Inclusive::Right | Inclusive::Both => true,
-            };
-            let r = T::gen_close_01_open(rng);
-            (r, stretch)
-        } else {
-            let stretch = match inclusive {
-                Inclusive::None | Inclusive::Right => false,
-                Inclusive::Left | Inclusive::Both => true,
-            };
-            let r = -T::gen_close_01_open(rng);
-            (r, stretch)
+        let stretch = match inclusive {
+            Inclusive::None | Inclusive::Right => false,
+            Inclusive::Left | Inclusive::Both => true,
         };
+        let r = -T::gen_close_01_open(rng);
+        (r, stretch)
+    };
 
-        let half_scale = if stretch {
-            let half_scale = (high_half - low_half) / T::max_rand();
-            if half_scale.is_finite() {
-                half_scale
-            } else {
-                // If the range is so extreme that it can't be stretched,
-                // use the standard scale.
-                high_half - low_half
-            }
+    let half_scale = if stretch {
+        let half_scale = (high_half - low_half) / T::max_rand();
+        if half_scale.is_finite() {
+            half_scale
         } else {
+            // If the range is so extreme that it can't be stretched,
+            // use the standard scale.
             high_half - low_half
-        };
+        }
+    } else {
+        high_half - low_half
+    };
 
-        r * half_scale + mid_point
-    }
+    r * half_scale + mid_point
 }
 
 #[cfg(test)]
@@ -211,13 +531,45 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn f64_triangular_has_expected_mean() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(low, mode, high) in [(0.0, 0.0, 1.0), (-2.0, 3.0, 5.0), (1.0, 4.0, 4.0)].iter() {
+            let total = 100_000;
+            let mut sum = 0.0;
+            let mut below_mode = 0;
+            for _ in 0..total {
+                let value = f64_triangular(&mut rng, low, mode, high);
+                assert!((low..=high).contains(&value));
+                sum += value;
+                if value < mode {
+                    below_mode += 1;
+                }
+            }
+
+            let mean = sum / total as f64;
+            let expected = (low + mode + high) / 3.0;
+            assert!(
+                (mean - expected).abs() < 0.01 * (high - low),
+                "mean should be ~{}, but is {}",
+                expected,
+                mean
+            );
+
+            // The CDF at the mode is `(mode - low) / (high - low)`.
+            let below_mode = below_mode as f64 / total as f64;
+            assert!((below_mode - (mode - low) / (high - low)).abs() < 0.01);
+        }
+    }
+
     #[test]
     fn f32_range_in_bounds() {
         let mut rng = Rng::with_seed(42);
 
         let range = -2.0..2.0;
         for _ in 0..10000 {
-            assert!(range.contains(&float_range_impl(&mut rng, range.clone())));
+            assert!(range.contains(&float_range_impl(&mut rng, range.clone()).unwrap()));
         }
     }
 
@@ -227,7 +579,7 @@ mod tests {
 
         let range = f32::MIN..f32::MAX;
         for _ in 0..10000 {
-            assert!(range.contains(&float_range_impl(&mut rng, range.clone())));
+            assert!(range.contains(&float_range_impl(&mut rng, range.clone()).unwrap()));
         }
     }
 
@@ -237,7 +589,196 @@ mod tests {
 
         let range = ..;
         for _ in 0..10000 {
-            assert!(&float_range_impl::<f32>(&mut rng, range).is_finite());
+            assert!(&float_range_impl::<f32>(&mut rng, range)
+                .unwrap()
+                .is_finite());
         }
     }
+
+    #[test]
+    fn f64_range_errors() {
+        let mut rng = Rng::with_seed(42);
+
+        assert_eq!(
+            float_range_impl(&mut rng, f64::NAN..1.0),
+            Err(RangeError::Nan)
+        );
+        assert_eq!(
+            float_range_impl(&mut rng, 0.0..=f64::NAN),
+            Err(RangeError::Nan)
+        );
+        assert_eq!(
+            float_range_impl(&mut rng, 2.0..1.0),
+            Err(RangeError::Inverted)
+        );
+        assert_eq!(float_range_impl(&mut rng, 1.0..1.0), Err(RangeError::Empty));
+        assert_eq!(
+            float_range_impl(&mut rng, (Bound::Excluded(1.0), Bound::Included(1.0))),
+            Err(RangeError::Empty)
+        );
+        assert_eq!(float_range_impl(&mut rng, 1.0..=1.0), Ok(1.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "range lower bound is greater than upper bound")]
+    fn f32_range_reversed_panics() {
+        let mut rng = Rng::with_seed(42);
+        f32(&mut rng, 5.0..1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range is empty")]
+    fn f32_range_empty_exclusive_panics() {
+        let mut rng = Rng::with_seed(42);
+        f32(&mut rng, 3.0..3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range bound is NaN")]
+    fn f64_range_nan_low_panics() {
+        let mut rng = Rng::with_seed(42);
+        f64(&mut rng, f64::NAN..1.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "range bound is NaN")]
+    fn f64_range_nan_high_panics() {
+        let mut rng = Rng::with_seed(42);
+        f64(&mut rng, 0.0..=f64::NAN);
+    }
+
+    #[test]
+    fn f64_range_infinite_bounds_are_finite() {
+        let mut rng = Rng::with_seed(42);
+
+        // Infinite bounds behave like unbounded ones.
+        for _ in 0..10000 {
+            let value = f64(&mut rng, 0.0..f64::INFINITY);
+            assert!(value.is_finite() && value >= 0.0, "value is {}", value);
+            let value = f64(&mut rng, f64::NEG_INFINITY..=-1.0);
+            assert!(value.is_finite() && value <= -1.0, "value is {}", value);
+            let value = f64(&mut rng, f64::NEG_INFINITY..f64::INFINITY);
+            assert!(value.is_finite(), "value is {}", value);
+        }
+
+        // A range of a single infinite value is still sampled as is.
+        assert_eq!(f64(&mut rng, f64::INFINITY..=f64::INFINITY), f64::INFINITY);
+    }
+
+    #[test]
+    fn f64_range_inclusive_reaches_both_endpoints() {
+        let mut rng = Rng::with_seed(42);
+
+        assert_eq!(closed_unit(0), 0.0);
+        assert_eq!(closed_unit(u64::MAX), 1.0);
+
+        for _ in 0..10000 {
+            let value = f64_inclusive(&mut rng, -3.0, 5.0);
+            assert!((-3.0..=5.0).contains(&value));
+        }
+
+        // In a range spanning just three floats, the endpoints are drawn
+        // often enough to be observed.
+        let (low, high) = (1.0, 1.0 + 2.0 * f64::EPSILON);
+        let (mut seen_low, mut seen_high) = (false, false);
+        for _ in 0..1000 {
+            let value = f64_inclusive(&mut rng, low, high);
+            assert!((low..=high).contains(&value));
+            seen_low |= value == low;
+            seen_high |= value == high;
+        }
+        assert!(seen_low && seen_high);
+
+        assert_eq!(f64_inclusive(&mut rng, 2.0, 2.0), 2.0);
+    }
+
+    #[test]
+    fn f64_range_avoiding_skips_hole() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut left = 0;
+        for _ in 0..10000 {
+            let value = f64_avoiding(&mut rng, 0.0..10.0, 2.0, 1.0);
+            assert!((0.0..10.0).contains(&value));
+            assert!((value - 2.0).abs() >= 1.0);
+            if value < 2.0 {
+                left += 1;
+            }
+        }
+        // The left part is 1 wide and the right part is 7 wide.
+        assert!((1050..=1450).contains(&left));
+
+        for _ in 0..100 {
+            let value = f64_avoiding(&mut rng, 0.0..=10.0, -5.0, 6.0);
+            assert!((1.0..=10.0).contains(&value));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "avoided point and radius must not be NaN")]
+    fn f64_range_avoiding_nan_panics() {
+        let mut rng = Rng::with_seed(42);
+        f64_avoiding(&mut rng, 0.0..10.0, f64::NAN, 1.0);
+    }
+
+    #[test]
+    fn f64_range_importance_reweights_to_target() {
+        let mut rng = Rng::with_seed(42);
+
+        // The target density 2x on [0, 1) has mean 2/3.
+        let total = 100000;
+        let mut weighted_sum = 0.0;
+        let mut weight_sum = 0.0;
+        for _ in 0..total {
+            let (x, weight) = f64_importance(&mut rng, 0.0, 1.0, |x| 2.0 * x);
+            assert!((0.0..1.0).contains(&x));
+            weighted_sum += x * weight;
+            weight_sum += weight;
+        }
+
+        let mean = weighted_sum / total as f64;
+        assert!((mean - 2.0 / 3.0).abs() < 0.01, "mean is {}", mean);
+        let mean_weight = weight_sum / total as f64;
+        assert!((mean_weight - 1.0).abs() < 0.01);
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn f64_range_shaped_bias() {
+        let mut rng = Rng::with_seed(42);
+
+        let n = 20000;
+        let mean = |rng: &mut Rng, shape: Shape| {
+            let mut sum = 0.0;
+            let mut edges = 0;
+            for _ in 0..n {
+                let value = f64_shaped(rng, 2.0..4.0, shape);
+                assert!((2.0..4.0).contains(&value));
+                sum += value;
+                if !(2.5..3.5).contains(&value) {
+                    edges += 1;
+                }
+            }
+            (sum / n as f64, edges as f64 / n as f64)
+        };
+
+        let (uniform, uniform_edges) = mean(&mut rng, Shape::Uniform);
+        assert!((uniform - 3.0).abs() < 0.02);
+        let (left, _) = mean(&mut rng, Shape::LeftSkewed);
+        assert!((left - 2.0 - 2.0 / 3.0).abs() < 0.02);
+        let (right, _) = mean(&mut rng, Shape::RightSkewed);
+        assert!((right - 2.0 - 4.0 / 3.0).abs() < 0.02);
+
+        // The outer half of the range holds 1/2 of the mass under the uniform
+        // distribution, 1/4 under the triangular and 2/3 under the arcsine.
+        assert!((uniform_edges - 0.5).abs() < 0.02);
+        let (center, center_edges) = mean(&mut rng, Shape::CenterPeaked);
+        assert!((center - 3.0).abs() < 0.02);
+        assert!((center_edges - 0.25).abs() < 0.02);
+        let (edge, edge_edges) = mean(&mut rng, Shape::EdgePeaked);
+        assert!((edge - 3.0).abs() < 0.02);
+        assert!((edge_edges - 2.0 / 3.0).abs() < 0.02);
+
+        assert_eq!(f64_shaped(&mut rng, 1.0..=1.0, Shape::EdgePeaked), 1.0);
+    }
 }
@@ -0,0 +1,652 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::float_normal::FloatMathExt;
+use crate::BaseRng;
+
+type Point = (f64, f64);
+#[cfg(feature = "alloc")]
+type Triangle = (Point, Point, Point);
+
+// TAU constant was stabilized in Rust 1.47. Our current MSRV is 1.43.
+const TAU: f64 = 2.0 * core::f64::consts::PI;
+
+pub(super) fn rotation_2d(rng: &mut impl BaseRng) -> f64 {
+    rng.f64() * TAU
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn rotation_matrix_2x2(rng: &mut impl BaseRng) -> [[f64; 2]; 2] {
+    let angle = rotation_2d(rng);
+    let (sin, cos) = (FloatMathExt::sin(angle), FloatMathExt::cos(angle));
+    [[cos, -sin], [sin, cos]]
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn small_rotation_quaternion(rng: &mut impl BaseRng, max_angle: f64) -> [f64; 4] {
+    assert!(max_angle >= 0.0, "max angle must not be negative");
+
+    let (x, y, z) = unit_vector_3d(rng);
+    let half_angle = rng.f64() * max_angle / 2.0;
+    let sin = FloatMathExt::sin(half_angle);
+    [FloatMathExt::cos(half_angle), x * sin, y * sin, z * sin]
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn unit_vector_2d(rng: &mut impl BaseRng) -> (f64, f64) {
+    let theta = rotation_2d(rng);
+    (FloatMathExt::cos(theta), FloatMathExt::sin(theta))
+}
+
+pub(super) fn unit_vector_2d_rejection(rng: &mut impl BaseRng) -> (f64, f64) {
+    loop {
+        // Pick a point uniformly in the unit disk by rejection from the square.
+        let x = rng.f64() * 2.0 - 1.0;
+        let y = rng.f64() * 2.0 - 1.0;
+        let r2 = x * x + y * y;
+        if r2 == 0.0 || r2 > 1.0 {
+            continue;
+        }
+
+        // Squaring the point as a complex number doubles its angle, which
+        // keeps the direction uniform, and dividing by the squared magnitude
+        // normalizes it without a square root (von Neumann's method).
+        return ((x * x - y * y) / r2, 2.0 * x * y / r2);
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f32_unit_vector_3d(rng: &mut impl BaseRng) -> (f32, f32, f32) {
+    unit_vector_3d_impl(rng)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn unit_vector_3d(rng: &mut impl BaseRng) -> (f64, f64, f64) {
+    unit_vector_3d_impl(rng)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn unit_vector_3d_impl<T: FloatMathExt + Copy>(rng: &mut impl BaseRng) -> (T, T, T) {
+    // By Archimedes' hat-box theorem, a uniform `z` and azimuth give a uniform
+    // point on the sphere.
+    let one = T::from_f64(1.0);
+    let z = T::from_f64(2.0) * T::gen(rng) - one;
+    let theta = T::TAU * T::gen(rng);
+    let r = (one - z * z).sqrt();
+    (r * theta.cos(), r * theta.sin(), z)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f32_point_in_sphere(rng: &mut impl BaseRng, radius: f32) -> (f32, f32, f32) {
+    point_in_sphere_impl(rng, radius)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn point_in_sphere(rng: &mut impl BaseRng, radius: f64) -> (f64, f64, f64) {
+    point_in_sphere_impl(rng, radius)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn point_in_sphere_impl<T: FloatMathExt + Copy>(rng: &mut impl BaseRng, radius: T) -> (T, T, T) {
+    assert!(radius >= T::from_f64(0.0), "radius must not be negative");
+
+    // The volume within distance `r` of the center grows with `r^3`, hence the
+    // cube root of a uniform draw.
+    let (x, y, z) = unit_vector_3d_impl::<T>(rng);
+    let r = radius * T::gen(rng).cbrt();
+    (r * x, r * y, r * z)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn f32_point_in_disk(rng: &mut impl BaseRng, radius: f32) -> (f32, f32) {
+    point_in_disk_impl(rng, radius)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub(super) fn point_in_disk(rng: &mut impl BaseRng, radius: f64) -> (f64, f64) {
+    point_in_disk_impl(rng, radius)
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+fn point_in_disk_impl<T: FloatMathExt + Copy>(rng: &mut impl BaseRng, radius: T) -> (T, T) {
+    assert!(radius >= T::from_f64(0.0), "radius must not be negative");
+
+    // The area within distance `r` of the center grows with `r^2`. Taking the
+    // square root of a uniform draw keeps the points from clustering in the
+    // center.
+    let r = radius * T::gen(rng).sqrt();
+    let theta = T::TAU * T::gen(rng);
+    (r * theta.cos(), r * theta.sin())
+}
+
+const GRID_STEPS_4: [(i8, i8); 4] = [(1, 0), (0, 1), (-1, 0), (0, -1)];
+const GRID_STEPS_8: [(i8, i8); 8] = [
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+];
+const GRID_STEPS_6: [(i8, i8, i8); 6] = [
+    (1, 0, 0),
+    (-1, 0, 0),
+    (0, 1, 0),
+    (0, -1, 0),
+    (0, 0, 1),
+    (0, 0, -1),
+];
+
+pub(super) fn grid_step(rng: &mut impl BaseRng, diagonal: bool) -> (i8, i8) {
+    if diagonal {
+        GRID_STEPS_8[rng.usize(..GRID_STEPS_8.len())]
+    } else {
+        GRID_STEPS_4[rng.usize(..GRID_STEPS_4.len())]
+    }
+}
+
+pub(super) fn grid_step_3d(rng: &mut impl BaseRng, diagonal: bool) -> (i8, i8, i8) {
+    if diagonal {
+        // Enumerate the 3x3x3 cube around the origin and skip its center,
+        // which is at index 13.
+        let mut i = rng.usize(..26) as i8;
+        if i >= 13 {
+            i += 1;
+        }
+        (i / 9 - 1, i / 3 % 3 - 1, i % 3 - 1)
+    } else {
+        GRID_STEPS_6[rng.usize(..GRID_STEPS_6.len())]
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub(super) fn fibonacci_sphere(
+    rng: &mut impl BaseRng,
+    n: usize,
+    jitter: f64,
+) -> Vec<(f64, f64, f64)> {
+    assert!((0.0..=1.0).contains(&jitter), "jitter must be in [0, 1]");
+
+    // The angle between consecutive points, pi * (3 - sqrt(5)).
+    let golden_angle = core::f64::consts::PI * (3.0 - FloatMathExt::sqrt(5.0));
+
+    (0..n)
+        .map(|i| {
+            // Point `i` is the center of the `i`-th of `n` bands of equal area
+            // along the z axis. Jitter moves it within its band and by up to
+            // half of the angular step to either side.
+            let (dz, dphi) = if jitter > 0.0 {
+                (rng.f64() - 0.5, rng.f64() - 0.5)
+            } else {
+                (0.0, 0.0)
+            };
+            let z = 1.0 - (2.0 * (i as f64 + 0.5 + jitter * dz)) / n as f64;
+            let phi = golden_angle * (i as f64 + jitter * dphi);
+
+            let r = FloatMathExt::sqrt(1.0 - z * z);
+            (r * FloatMathExt::cos(phi), r * FloatMathExt::sin(phi), z)
+        })
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn point_in_polygon(rng: &mut impl BaseRng, vertices: &[Point]) -> Point {
+    assert!(vertices.len() >= 3, "polygon must have at least 3 vertices");
+
+    let triangles = triangulate(vertices);
+    let areas: Vec<f64> = triangles
+        .iter()
+        .map(|&(a, b, c)| cross(a, b, c).abs() / 2.0)
+        .collect();
+    let total: f64 = areas.iter().sum();
+    assert!(total > 0.0, "polygon must have a positive area");
+
+    // Choose a triangle with probability proportional to its area.
+    let mut target = rng.f64() * total;
+    let mut chosen = triangles[triangles.len() - 1];
+    for (&triangle, &area) in triangles.iter().zip(areas.iter()) {
+        if target < area {
+            chosen = triangle;
+            break;
+        }
+        target -= area;
+    }
+
+    let (a, b, c) = chosen;
+    point_in_triangle(rng, a, b, c)
+}
+
+/// Split a simple polygon into triangles using ear clipping.
+#[cfg(feature = "alloc")]
+fn triangulate(vertices: &[Point]) -> Vec<Triangle> {
+    let mut remaining: Vec<Point> = vertices.to_vec();
+
+    // Ear clipping below assumes counter-clockwise orientation.
+    let signed_area: f64 = (0..remaining.len())
+        .map(|i| {
+            let (x1, y1) = remaining[i];
+            let (x2, y2) = remaining[(i + 1) % remaining.len()];
+            x1 * y2 - x2 * y1
+        })
+        .sum();
+    if signed_area < 0.0 {
+        remaining.reverse();
+    }
+
+    let mut triangles = Vec::with_capacity(remaining.len() - 2);
+    while remaining.len() > 3 {
+        let n = remaining.len();
+        let corner = |i: usize| {
+            (
+                remaining[(i + n - 1) % n],
+                remaining[i],
+                remaining[(i + 1) % n],
+            )
+        };
+
+        let ear = (0..n).find(|&i| {
+            let (a, b, c) = corner(i);
+            cross(a, b, c) > 0.0
+                && remaining
+                    .iter()
+                    .all(|&p| p == a || p == b || p == c || !triangle_contains(a, b, c, p))
+        });
+
+        match ear {
+            Some(i) => {
+                triangles.push(corner(i));
+                remaining.remove(i);
+            }
+            None => {
+                // Only collinear vertices can be left without an ear in a
+                // simple polygon. They don't contribute any area.
+                let i = (0..n)
+                    .find(|&i| {
+                        let (a, b, c) = corner(i);
+                        cross(a, b, c) == 0.0
+                    })
+                    .expect("polygon must be simple");
+                remaining.remove(i);
+            }
+        }
+    }
+
+    triangles.push((remaining[0], remaining[1], remaining[2]));
+    triangles
+}
+
+/// Twice the signed area of the triangle `abc`. Positive for counter-clockwise
+/// orientation.
+#[cfg(feature = "alloc")]
+fn cross(a: Point, b: Point, c: Point) -> f64 {
+    (b.0 - a.0) * (c.1 - a.1) - (b.1 - a.1) * (c.0 - a.0)
+}
+
+#[cfg(feature = "alloc")]
+fn triangle_contains(a: Point, b: Point, c: Point, p: Point) -> bool {
+    cross(a, b, p) >= 0.0 && cross(b, c, p) >= 0.0 && cross(c, a, p) >= 0.0
+}
+
+pub(super) fn point_in_triangle(rng: &mut impl BaseRng, a: Point, b: Point, c: Point) -> Point {
+    // Sample the parallelogram spanned by the triangle edges and fold the half
+    // outside of the triangle back into it.
+    let (mut u, mut v) = (rng.f64(), rng.f64());
+    if u + v > 1.0 {
+        u = 1.0 - u;
+        v = 1.0 - v;
+    }
+
+    (
+        a.0 + u * (b.0 - a.0) + v * (c.0 - a.0),
+        a.1 + u * (b.1 - a.1) + v * (c.1 - a.1),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn rotation_2d_in_bounds() {
+        let mut rng = Rng::with_seed(42);
+
+        for _ in 0..10000 {
+            assert!((0.0..TAU).contains(&rotation_2d(&mut rng)));
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn rotation_matrix_2x2_is_orthonormal() {
+        let mut rng = Rng::with_seed(42);
+
+        for _ in 0..1000 {
+            let [[a, b], [c, d]] = rotation_matrix_2x2(&mut rng);
+
+            assert!((a * d - b * c - 1.0).abs() < 1e-12);
+            assert!((a * a + b * b - 1.0).abs() < 1e-12);
+            assert!((c * c + d * d - 1.0).abs() < 1e-12);
+            assert!((a * c + b * d).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn small_rotation_quaternion_is_bounded_unit_quaternion() {
+        let mut rng = Rng::with_seed(42);
+
+        for &max_angle in [0.0, 0.1, 1.0, 3.0].iter() {
+            // The rotation angle is `2 * acos(w)`, so it is at most `max_angle`
+            // if `w >= cos(max_angle / 2)`.
+            let min_w = FloatMathExt::cos(max_angle / 2.0);
+            for _ in 0..1000 {
+                let [w, x, y, z] = small_rotation_quaternion(&mut rng, max_angle);
+                assert!((w * w + x * x + y * y + z * z - 1.0).abs() < 1e-12);
+                assert!(w >= min_w - 1e-12);
+            }
+        }
+    }
+
+    fn assert_uniform_on_circle(mut sample: impl FnMut() -> (f64, f64)) {
+        let total = 100_000;
+        let mut sum = (0.0, 0.0);
+        let mut quadrants = [0; 4];
+        for _ in 0..total {
+            let (x, y) = sample();
+            assert!((x * x + y * y - 1.0).abs() < 1e-12);
+            sum = (sum.0 + x, sum.1 + y);
+            quadrants[(x >= 0.0) as usize * 2 + (y >= 0.0) as usize] += 1;
+        }
+
+        // Each component has variance 1/2, so the standard error of the mean
+        // is below 0.003.
+        assert!((sum.0 / total as f64).abs() < 0.015);
+        assert!((sum.1 / total as f64).abs() < 0.015);
+        for &count in quadrants.iter() {
+            assert!(
+                count > total / 4 * 95 / 100 && count < total / 4 * 105 / 100,
+                "quadrant sampled {} times",
+                count
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn unit_vector_2d_is_uniform_on_circle() {
+        let mut rng = Rng::with_seed(42);
+        assert_uniform_on_circle(|| unit_vector_2d(&mut rng));
+    }
+
+    #[test]
+    fn unit_vector_2d_rejection_is_uniform_on_circle() {
+        let mut rng = Rng::with_seed(42);
+        assert_uniform_on_circle(|| unit_vector_2d_rejection(&mut rng));
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn unit_vector_3d_is_uniform_on_sphere() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 100_000;
+        let mut sum = (0.0, 0.0, 0.0);
+        for _ in 0..total {
+            let (x, y, z) = unit_vector_3d(&mut rng);
+            assert!((x * x + y * y + z * z - 1.0).abs() < 1e-12);
+            sum = (sum.0 + x, sum.1 + y, sum.2 + z);
+        }
+
+        // Each component has variance 1/3, so the standard error of the mean
+        // is below 0.002.
+        let total = total as f64;
+        for &mean in [sum.0 / total, sum.1 / total, sum.2 / total].iter() {
+            assert!(mean.abs() < 0.01, "component mean is {}", mean);
+        }
+
+        for _ in 0..1000 {
+            let (x, y, z) = f32_unit_vector_3d(&mut rng);
+            assert!((x * x + y * y + z * z - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn point_in_sphere_is_uniform_by_volume() {
+        let mut rng = Rng::with_seed(42);
+
+        let radius = 2.0;
+        let total = 40000;
+        // Four shells of equal volume.
+        let mut counts = [0; 4];
+        for _ in 0..total {
+            let (x, y, z) = point_in_sphere(&mut rng, radius);
+            let r = FloatMathExt::sqrt(x * x + y * y + z * z);
+            assert!(r <= radius * (1.0 + 1e-12));
+            let shell = (4.0 * (r / radius) * (r / radius) * (r / radius)) as usize;
+            counts[shell.min(3)] += 1;
+        }
+
+        let expected = total / 4;
+        for &count in counts.iter() {
+            assert!(
+                count > expected * 95 / 100 && count < expected * 105 / 100,
+                "shell sampled {} times, expected ~{}",
+                count,
+                expected
+            );
+        }
+
+        for _ in 0..1000 {
+            let (x, y, z) = f32_point_in_sphere(&mut rng, 1.0);
+            assert!(x * x + y * y + z * z <= 1.0 + 1e-5);
+        }
+    }
+
+    #[test]
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn point_in_disk_is_uniform_by_area() {
+        let mut rng = Rng::with_seed(42);
+
+        let radius = 3.0;
+        let total = 40000;
+        // Four annuli of equal area.
+        let mut counts = [0; 4];
+        for _ in 0..total {
+            let (x, y) = point_in_disk(&mut rng, radius);
+            let r2 = x * x + y * y;
+            assert!(r2 <= radius * radius * (1.0 + 1e-12));
+            let annulus = (4.0 * r2 / (radius * radius)) as usize;
+            counts[annulus.min(3)] += 1;
+        }
+
+        let expected = total / 4;
+        for &count in counts.iter() {
+            assert!(
+                count > expected * 95 / 100 && count < expected * 105 / 100,
+                "annulus sampled {} times, expected ~{}",
+                count,
+                expected
+            );
+        }
+
+        for _ in 0..1000 {
+            let (x, y) = f32_point_in_disk(&mut rng, 1.0);
+            assert!(x * x + y * y <= 1.0 + 1e-6);
+        }
+    }
+
+    #[test]
+    fn grid_step_is_uniform_over_neighbors() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 27000;
+        for &diagonal in [false, true].iter() {
+            let mut counts = [[0; 3]; 3];
+            for _ in 0..total {
+                let (dx, dy) = grid_step(&mut rng, diagonal);
+                counts[(dx + 1) as usize][(dy + 1) as usize] += 1;
+            }
+
+            let neighbors = if diagonal { 8 } else { 4 };
+            let expected = total / neighbors;
+            for (dx, row) in counts.iter().enumerate() {
+                for (dy, &count) in row.iter().enumerate() {
+                    let is_neighbor = match (dx, dy) {
+                        (1, 1) => false,
+                        (1, _) | (_, 1) => true,
+                        _ => diagonal,
+                    };
+                    if is_neighbor {
+                        assert!(
+                            count > expected * 9 / 10 && count < expected * 11 / 10,
+                            "offset ({}, {}) sampled {} times, expected ~{}",
+                            dx as i8 - 1,
+                            dy as i8 - 1,
+                            count,
+                            expected
+                        );
+                    } else {
+                        assert_eq!(count, 0);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn grid_step_3d_is_uniform_over_neighbors() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 26000;
+        for &diagonal in [false, true].iter() {
+            let mut counts = [[[0; 3]; 3]; 3];
+            for _ in 0..total {
+                let (dx, dy, dz) = grid_step_3d(&mut rng, diagonal);
+                counts[(dx + 1) as usize][(dy + 1) as usize][(dz + 1) as usize] += 1;
+            }
+
+            let neighbors = if diagonal { 26 } else { 6 };
+            let expected = total / neighbors;
+            let mut seen = 0;
+            for (dx, plane) in counts.iter().enumerate() {
+                for (dy, row) in plane.iter().enumerate() {
+                    for (dz, &count) in row.iter().enumerate() {
+                        let off_center = [dx, dy, dz].iter().filter(|&&d| d != 1).count();
+                        let is_neighbor = off_center == 1 || (diagonal && off_center > 1);
+                        if is_neighbor {
+                            assert!(count > expected * 8 / 10 && count < expected * 12 / 10);
+                            seen += 1;
+                        } else {
+                            assert_eq!(count, 0);
+                        }
+                    }
+                }
+            }
+            assert_eq!(seen, neighbors);
+        }
+    }
+
+    #[test]
+    fn point_in_triangle_is_uniform() {
+        let mut rng = Rng::with_seed(42);
+
+        let side =
+            |a: Point, b: Point, p: Point| (b.0 - a.0) * (p.1 - a.1) - (b.1 - a.1) * (p.0 - a.0);
+        for &(a, b, c) in [
+            ((0.0, 0.0), (1.0, 0.0), (0.0, 1.0)),
+            ((-2.0, 1.0), (3.0, 5.0), (4.0, -3.0)),
+        ]
+        .iter()
+        {
+            // Orient the triangle counter-clockwise for the containment check.
+            let (b, c) = if side(a, b, c) > 0.0 { (b, c) } else { (c, b) };
+
+            let total = 100_000;
+            let mut sum = (0.0, 0.0);
+            for _ in 0..total {
+                let p = point_in_triangle(&mut rng, a, b, c);
+                // Allow for rounding at the edges.
+                assert!(side(a, b, p) >= -1e-9 && side(b, c, p) >= -1e-9 && side(c, a, p) >= -1e-9);
+                sum = (sum.0 + p.0, sum.1 + p.1);
+            }
+
+            let centroid = ((a.0 + b.0 + c.0) / 3.0, (a.1 + b.1 + c.1) / 3.0);
+            let mean = (sum.0 / total as f64, sum.1 / total as f64);
+            assert!(
+                (mean.0 - centroid.0).abs() < 0.02 && (mean.1 - centroid.1).abs() < 0.02,
+                "mean {:?}, expected ~{:?}",
+                mean,
+                centroid
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn point_in_polygon_is_uniform_by_area() {
+        let mut rng = Rng::with_seed(42);
+
+        // A clockwise square with an extra vertex on one of its edges.
+        let square = [(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 1.0), (2.0, 0.0)];
+        let total = 10000;
+        let mut quadrants = [0; 4];
+        for _ in 0..total {
+            let (x, y) = point_in_polygon(&mut rng, &square);
+            assert!((0.0..=2.0).contains(&x) && (0.0..=2.0).contains(&y));
+            quadrants[(x >= 1.0) as usize * 2 + (y >= 1.0) as usize] += 1;
+        }
+        for &count in quadrants.iter() {
+            assert!(
+                (2300..=2700).contains(&count),
+                "quadrant sampled {} times",
+                count
+            );
+        }
+
+        // A concave L shape made of three unit squares.
+        let l_shape = [
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (2.0, 1.0),
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (0.0, 2.0),
+        ];
+        let mut upper = 0;
+        for _ in 0..total {
+            let (x, y) = point_in_polygon(&mut rng, &l_shape);
+            assert!((0.0..=2.0).contains(&x) && (0.0..=2.0).contains(&y));
+            assert!(x <= 1.0 || y <= 1.0);
+            if y > 1.0 {
+                upper += 1;
+            }
+        }
+        assert!((3000..=3700).contains(&upper));
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn fibonacci_sphere_points_are_on_unit_sphere() {
+        let mut rng = Rng::with_seed(42);
+
+        for &jitter in [0.0, 0.5, 1.0].iter() {
+            let points = fibonacci_sphere(&mut rng, 200, jitter);
+            assert_eq!(points.len(), 200);
+            for &(x, y, z) in points.iter() {
+                assert!((x * x + y * y + z * z - 1.0).abs() < 1e-12);
+            }
+        }
+
+        let mut other = Rng::with_seed(7);
+        assert_eq!(
+            fibonacci_sphere(&mut rng, 100, 0.0),
+            fibonacci_sphere(&mut other, 100, 0.0)
+        );
+    }
+}
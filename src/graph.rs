@@ -0,0 +1,136 @@
+use alloc::vec::Vec;
+use core::ops::Range;
+
+use crate::{float_range, BaseRng};
+
+pub(super) fn random_tree(rng: &mut impl BaseRng, n: usize) -> Vec<(usize, usize)> {
+    assert!(n > 0, "tree must have at least one node");
+    if n == 1 {
+        return Vec::new();
+    }
+
+    // Every labeled tree corresponds to exactly one Prüfer sequence of length
+    // n - 2, so decoding a uniformly random sequence gives a uniformly random
+    // tree. The linear-time decoding is described here:
+    // https://cp-algorithms.com/graph/pruefer_code.html
+    let code: Vec<usize> = (0..n - 2).map(|_| rng.usize(..n)).collect();
+
+    let mut degree = alloc::vec![1; n];
+    for &v in code.iter() {
+        degree[v] += 1;
+    }
+
+    let mut ptr = 0;
+    while degree[ptr] != 1 {
+        ptr += 1;
+    }
+    let mut leaf = ptr;
+
+    let mut edges = Vec::with_capacity(n - 1);
+    for &v in code.iter() {
+        edges.push((leaf, v));
+
+        degree[v] -= 1;
+        if degree[v] == 1 && v < ptr {
+            leaf = v;
+        } else {
+            ptr += 1;
+            while degree[ptr] != 1 {
+                ptr += 1;
+            }
+            leaf = ptr;
+        }
+    }
+    edges.push((leaf, n - 1));
+
+    edges
+}
+
+pub(super) fn random_weighted_graph(
+    rng: &mut impl BaseRng,
+    n: usize,
+    p: f64,
+    weight_range: Range<f64>,
+) -> Vec<(usize, usize, f64)> {
+    assert!(
+        (0.0..=1.0).contains(&p),
+        "edge probability must be in [0, 1]"
+    );
+    assert!(
+        weight_range.start < weight_range.end,
+        "weight range must not be empty"
+    );
+
+    // Erdős–Rényi G(n, p): each of the possible edges is present independently
+    // with probability p.
+    let mut edges = Vec::new();
+    for i in 0..n {
+        for j in i + 1..n {
+            if rng.f64() < p {
+                let weight = float_range::f64(rng, weight_range.clone());
+                edges.push((i, j, weight));
+            }
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    fn find(parents: &mut [usize], mut x: usize) -> usize {
+        while parents[x] != x {
+            parents[x] = parents[parents[x]];
+            x = parents[x];
+        }
+        x
+    }
+
+    #[test]
+    fn random_tree_is_connected_and_acyclic() {
+        let mut rng = Rng::with_seed(42);
+
+        for n in 1..100 {
+            let edges = random_tree(&mut rng, n);
+            assert_eq!(edges.len(), n - 1);
+
+            // With n - 1 edges, having no cycle implies being connected.
+            let mut parents: Vec<usize> = (0..n).collect();
+            for &(a, b) in edges.iter() {
+                assert!(a < n && b < n);
+                let (ra, rb) = (find(&mut parents, a), find(&mut parents, b));
+                assert_ne!(ra, rb, "edge ({}, {}) closes a cycle", a, b);
+                parents[ra] = rb;
+            }
+        }
+    }
+
+    #[test]
+    fn random_weighted_graph_is_simple() {
+        let mut rng = Rng::with_seed(42);
+
+        let n = 50;
+        let edges = random_weighted_graph(&mut rng, n, 0.3, 1.0..5.0);
+
+        let mut seen = alloc::vec![false; n * n];
+        for &(a, b, weight) in edges.iter() {
+            assert!(a < b && b < n);
+            assert!(!seen[a * n + b]);
+            seen[a * n + b] = true;
+            assert!((1.0..5.0).contains(&weight));
+        }
+
+        // The expected number of edges is 0.3 * 50 * 49 / 2 = 367.5.
+        assert!((300..=435).contains(&edges.len()));
+
+        assert!(random_weighted_graph(&mut rng, n, 0.0, 0.0..1.0).is_empty());
+        assert_eq!(
+            random_weighted_graph(&mut rng, n, 1.0, 0.0..1.0).len(),
+            n * (n - 1) / 2
+        );
+    }
+}
@@ -0,0 +1,81 @@
+use alloc::vec::Vec;
+
+use crate::BaseRng;
+
+pub(super) fn random_intervals(
+    rng: &mut impl BaseRng,
+    span: (f64, f64),
+    count: usize,
+    max_len: f64,
+) -> Vec<(f64, f64)> {
+    let (low, high) = span;
+    assert!(low <= high, "span must not be inverted");
+    assert!(max_len > 0.0, "maximum interval length must be positive");
+
+    let width = high - low;
+
+    // Pick the lengths first and keep only as many intervals as fit into the
+    // span.
+    let mut lengths = Vec::with_capacity(count);
+    let mut used = 0.0;
+    for _ in 0..count {
+        // Use (0, max_len] so that no interval is empty.
+        let len = (1.0 - rng.f64()) * max_len;
+        if used + len > width {
+            break;
+        }
+        used += len;
+        lengths.push(len);
+    }
+
+    // Distribute the free space into random gaps between the intervals. The
+    // sorted offsets are the gaps accumulated up to each interval.
+    let free = width - used;
+    let mut offsets: Vec<f64> = lengths.iter().map(|_| rng.f64() * free).collect();
+    offsets.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let mut start = low;
+    let mut prev_offset = 0.0;
+    lengths
+        .into_iter()
+        .zip(offsets)
+        .map(|(len, offset)| {
+            start += offset - prev_offset;
+            prev_offset = offset;
+
+            let interval = (start, start + len);
+            start += len;
+            interval
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn random_intervals_are_sorted_and_disjoint() {
+        let mut rng = Rng::with_seed(42);
+
+        for &(count, max_len) in [(10, 5.0), (100, 5.0), (0, 1.0)].iter() {
+            for _ in 0..100 {
+                let intervals = random_intervals(&mut rng, (-10.0, 90.0), count, max_len);
+
+                if count == 10 {
+                    assert_eq!(intervals.len(), count);
+                }
+                assert!(intervals.len() <= count);
+                for &(start, end) in intervals.iter() {
+                    assert!(-10.0 <= start && start < end && end <= 90.0);
+                    assert!(end - start <= max_len);
+                }
+                for pair in intervals.windows(2) {
+                    assert!(pair[0].1 <= pair[1].0);
+                }
+            }
+        }
+    }
+}
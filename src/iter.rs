@@ -0,0 +1,159 @@
+use crate::bernoulli::bool_with_prob;
+use crate::Rng;
+
+/// An iterator that yields random 64-bit floating point numbers in the
+/// `[0, 1)` range forever.
+///
+/// Created by [`iter_f64`](crate::RngExt::iter_f64).
+#[derive(Debug)]
+pub struct RngIter<'a> {
+    rng: &'a mut Rng,
+}
+
+impl Iterator for RngIter<'_> {
+    type Item = f64;
+
+    #[inline]
+    fn next(&mut self) -> Option<f64> {
+        Some(self.rng.f64())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// An iterator that yields the results of calling a closure with an RNG
+/// forever.
+///
+/// Created by [`iter_with`](crate::RngExt::iter_with).
+#[derive(Debug)]
+pub struct RngIterWith<'a, F> {
+    rng: &'a mut Rng,
+    f: F,
+}
+
+impl<F: FnMut(&mut Rng) -> T, T> Iterator for RngIterWith<'_, F> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        Some((self.f)(self.rng))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+/// An iterator that yields runs of equal booleans forever.
+///
+/// Created by [`bool_streak`](crate::RngExt::bool_streak).
+#[derive(Debug)]
+pub struct BoolStreak<'a> {
+    rng: &'a mut Rng,
+    switch_prob: f64,
+    previous: Option<bool>,
+}
+
+impl Iterator for BoolStreak<'_> {
+    type Item = bool;
+
+    #[inline]
+    fn next(&mut self) -> Option<bool> {
+        let value = match self.previous {
+            Some(previous) => previous != bool_with_prob(self.rng, self.switch_prob),
+            None => self.rng.bool(),
+        };
+        self.previous = Some(value);
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+pub(super) fn iter_f64(rng: &mut Rng) -> RngIter<'_> {
+    RngIter { rng }
+}
+
+pub(super) fn iter_with<F: FnMut(&mut Rng) -> T, T>(rng: &mut Rng, f: F) -> RngIterWith<'_, F> {
+    RngIterWith { rng, f }
+}
+
+pub(super) fn bool_streak(rng: &mut Rng, switch_prob: f64) -> BoolStreak<'_> {
+    debug_assert!(
+        (0.0..=1.0).contains(&switch_prob),
+        "probability must be in [0, 1], but got {}",
+        switch_prob
+    );
+
+    BoolStreak {
+        rng,
+        switch_prob,
+        previous: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn iterators_yield_rng_values() {
+        let mut rng = Rng::with_seed(42);
+        let mut expected = Rng::with_seed(42);
+
+        for x in iter_f64(&mut rng).take(100) {
+            assert_eq!(x, expected.f64());
+        }
+
+        let mut count = 0;
+        for x in iter_with(&mut rng, |rng| rng.u32(..10) * 2).take(100) {
+            assert_eq!(x, expected.u32(..10) * 2);
+            count += 1;
+        }
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    fn bool_streak_has_expected_run_length() {
+        let mut rng = Rng::with_seed(42);
+
+        for &switch_prob in [0.02, 0.5].iter() {
+            let total = 200_000;
+            let mut runs = 1;
+            let mut trues = 0;
+            let mut previous = None;
+            for value in bool_streak(&mut rng, switch_prob).take(total) {
+                if previous.map_or(false, |previous| previous != value) {
+                    runs += 1;
+                }
+                if value {
+                    trues += 1;
+                }
+                previous = Some(value);
+            }
+
+            // Runs end with probability `switch_prob` after every value.
+            let mean_run = total as f64 / runs as f64;
+            let expected = 1.0 / switch_prob;
+            assert!(
+                (mean_run - expected).abs() < 0.05 * expected,
+                "mean run length should be ~{}, but is {}",
+                expected,
+                mean_run
+            );
+            let trues = trues as f64 / total as f64;
+            assert!((trues - 0.5).abs() < 0.05, "{} of values are true", trues);
+        }
+
+        let mut streak = bool_streak(&mut rng, 0.0);
+        let first = streak.next().unwrap();
+        assert!(streak.take(100).all(|value| value == first));
+    }
+}
@@ -0,0 +1,102 @@
+use alloc::string::String;
+
+use serde_json_dep::{Map, Number, Value};
+
+use crate::BaseRng;
+
+/// The maximum number of elements in a generated array or object.
+const MAX_LEN: usize = 4;
+
+/// Characters used for strings, including some that need escaping in JSON.
+const CHARS: &[char] = &[
+    'a', 'b', 'c', 'x', 'y', 'z', 'A', 'Z', '0', '9', ' ', '_', '"', '\\', '/', '\n', '\t',
+    '\u{0}', 'é', 'ß', '中', '😀',
+];
+
+pub(super) fn random_json(rng: &mut impl BaseRng, max_depth: usize) -> Value {
+    // Containers are picked a third of the time with on average 2 elements
+    // each, so the expected number of children per value stays below 1 and
+    // the output stays small even for a large `max_depth`.
+    let kinds = if max_depth == 0 { 4 } else { 6 };
+    match rng.usize(..kinds) {
+        0 => Value::Null,
+        1 => Value::Bool(rng.bool()),
+        2 => Value::Number(random_number(rng)),
+        3 => Value::String(random_string(rng)),
+        4 => {
+            let len = rng.usize(..=MAX_LEN);
+            Value::Array((0..len).map(|_| random_json(rng, max_depth - 1)).collect())
+        }
+        _ => {
+            let len = rng.usize(..=MAX_LEN);
+            let mut map = Map::new();
+            for _ in 0..len {
+                let key = random_string(rng);
+                map.insert(key, random_json(rng, max_depth - 1));
+            }
+            Value::Object(map)
+        }
+    }
+}
+
+fn random_number(rng: &mut impl BaseRng) -> Number {
+    match rng.usize(..3) {
+        0 => Number::from(rng.u64() as i64),
+        1 => Number::from(rng.u64()),
+        // The mantissa and exponent are kept moderate so the value is always
+        // finite.
+        _ => {
+            let mantissa = rng.f64() * 2.0 - 1.0;
+            let exponent = rng.usize(..=20) as i32 - 10;
+            let mut value = mantissa;
+            for _ in 0..exponent.abs() {
+                if exponent < 0 {
+                    value /= 10.0;
+                } else {
+                    value *= 10.0;
+                }
+            }
+            Number::from_f64(value).unwrap_or_else(|| Number::from(0))
+        }
+    }
+}
+
+fn random_string(rng: &mut impl BaseRng) -> String {
+    let len = rng.usize(..=8);
+    (0..len).map(|_| CHARS[rng.usize(..CHARS.len())]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    fn depth(value: &Value) -> usize {
+        match value {
+            Value::Array(values) => 1 + values.iter().map(depth).max().unwrap_or(0),
+            Value::Object(map) => 1 + map.values().map(depth).max().unwrap_or(0),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn random_json_respects_max_depth() {
+        let mut rng = Rng::with_seed(42);
+
+        for _ in 0..1000 {
+            let value = random_json(&mut rng, 0);
+            assert!(!value.is_array() && !value.is_object());
+        }
+
+        let mut deepest = 0;
+        for max_depth in 1..6 {
+            for _ in 0..1000 {
+                let value = random_json(&mut rng, max_depth);
+                assert!(depth(&value) <= max_depth);
+                deepest = deepest.max(depth(&value));
+            }
+        }
+        assert!(deepest > 1);
+    }
+}
@@ -40,12 +40,14 @@
 //! - `std` (enabled by default): Enables the `std` library. Freestanding functions only work with this
 //!   feature enabled. Also enables the `fastrand/std` feature.
 //! - `libm`: Uses [`libm`] dependency for math functions in `no_std` environment.
+//! - `serde_json`: Enables generating random [`serde_json`] values.
 //!
 //! Note that some functions are not available in `no_std` context if `libm` feature is not enabled.
 //!
 //! [`fastrand`]: https://crates.io/crates/fastrand
 //! [`fastrand::Rng`]: https://docs.rs/fastrand/latest/fastrand/struct.Rng.html
 //! [`libm`]: https://crates.io/crates/libm
+//! [`serde_json`]: https://crates.io/crates/serde_json
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code, future_incompatible, missing_docs)]
@@ -56,18 +58,104 @@
     html_logo_url = "https://raw.githubusercontent.com/smol-rs/smol/master/assets/images/logo_fullsize_transparent.png"
 )]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+mod bernoulli;
+mod bits;
+#[cfg(feature = "alloc")]
+mod color;
+mod dice;
+mod discrete;
+mod dist;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod entropy;
+mod fill;
+mod float_bits;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod float_dist;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod float_exponential;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod float_gamma;
 mod float_normal;
 mod float_range;
+mod geometry;
+#[cfg(feature = "alloc")]
+mod graph;
+#[cfg(feature = "alloc")]
+mod intervals;
+mod iter;
+#[cfg(feature = "serde_json")]
+mod json;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod matrix;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+mod mixture;
+mod net;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod poisson;
+mod rejection;
+mod seq;
+#[cfg(feature = "alloc")]
+mod string;
+mod time;
+mod uuid;
+mod weighted;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod ziggurat_tables;
 
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::ops::Range;
 use core::ops::RangeBounds;
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr};
 
+#[cfg(feature = "alloc")]
+pub use color::PaletteScheme;
+pub use dice::DiceError;
+pub use dist::{DistExt, DistIter, Distribution, Uniform};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use dist::{Exponential, Normal};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use entropy::shannon_entropy;
 pub use fastrand::{self, Rng};
+pub use float_bits::f64_from_bits;
+pub use float_range::RangeError;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use float_range::Shape;
+pub use iter::{BoolStreak, RngIter, RngIterWith};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use matrix::MatrixDist;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub use mixture::GaussianMixture;
+#[cfg(feature = "alloc")]
+pub use weighted::AliasTable;
 
 trait BaseRng {
     fn f32(&mut self) -> f32;
     fn f64(&mut self) -> f64;
     fn bool(&mut self) -> bool;
+    fn u32(&mut self) -> u32;
+    fn u64(&mut self) -> u64;
     fn u128(&mut self) -> u128;
+    fn u64_range(&mut self, range: impl RangeBounds<u64>) -> u64;
+    fn usize(&mut self, range: impl RangeBounds<usize>) -> usize;
+
+    /// Generate a random float in (0, 1) range.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_open(&mut self) -> f64 {
+        loop {
+            let r = self.f64();
+
+            if r != 0.0 {
+                return r;
+            }
+        }
+    }
 }
 
 impl BaseRng for Rng {
@@ -84,9 +172,25 @@ impl BaseRng for Rng {
         Rng::bool(self)
     }
     #[inline]
+    fn u32(&mut self) -> u32 {
+        Rng::u32(self, ..)
+    }
+    #[inline]
+    fn u64(&mut self) -> u64 {
+        Rng::u64(self, ..)
+    }
+    #[inline]
     fn u128(&mut self) -> u128 {
         Rng::u128(self, ..)
     }
+    #[inline]
+    fn u64_range(&mut self, range: impl RangeBounds<u64>) -> u64 {
+        Rng::u64(self, range)
+    }
+    #[inline]
+    fn usize(&mut self, range: impl RangeBounds<usize>) -> usize {
+        Rng::usize(self, range)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -107,45 +211,151 @@ impl BaseRng for GlobalRng {
         fastrand::bool()
     }
     #[inline]
+    fn u32(&mut self) -> u32 {
+        fastrand::u32(..)
+    }
+    #[inline]
+    fn u64(&mut self) -> u64 {
+        fastrand::u64(..)
+    }
+    #[inline]
     fn u128(&mut self) -> u128 {
         fastrand::u128(..)
     }
+    #[inline]
+    fn u64_range(&mut self, range: impl RangeBounds<u64>) -> u64 {
+        fastrand::u64(range)
+    }
+    #[inline]
+    fn usize(&mut self, range: impl RangeBounds<usize>) -> usize {
+        fastrand::usize(range)
+    }
+}
+
+/// Generate standard Gumbel noise, i.e. [`f64_gumbel(0.0, 1.0)`](f64_gumbel).
+///
+/// This is the noise added to logits in the Gumbel-max and Gumbel-softmax
+/// tricks.
+#[cfg(feature = "std")]
+pub fn gumbel_noise() -> f64 {
+    f64_gumbel(0.0, 1.0)
+}
+
+/// Run a closure with the global thread-local RNG seeded with `seed`.
+///
+/// The previous state of the global RNG is restored after the closure returns, even if it panics.
+/// This makes code that uses the freestanding functions reproducible, e.g. in tests.
+///
+/// ```
+/// use fastrand_contrib::{f64_range, with_seeded_global};
+///
+/// let a = with_seeded_global(7, || f64_range(0.0..1.0));
+/// let b = with_seeded_global(7, || f64_range(0.0..1.0));
+/// assert_eq!(a, b);
+/// ```
+#[cfg(feature = "std")]
+pub fn with_seeded_global<R>(seed: u64, f: impl FnOnce() -> R) -> R {
+    struct RestoreSeed(u64);
+
+    impl Drop for RestoreSeed {
+        fn drop(&mut self) {
+            fastrand::seed(self.0);
+        }
+    }
+
+    let _restore = RestoreSeed(fastrand::get_seed());
+    fastrand::seed(seed);
+    f()
+}
+
+/// Reseed the global thread-local RNG from the operating system's entropy
+/// source.
+///
+/// On Unix, the seed is read from `/dev/urandom`. On other platforms, an error
+/// is returned. The RNG is left unchanged if the entropy can't be obtained, so
+/// a weak seed is never used silently.
+///
+/// Note that [`fastrand::Rng`] is not a cryptographically secure RNG even with
+/// a strong seed.
+#[cfg(feature = "std")]
+pub fn reseed_from_os() -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::io::Read;
+
+        let mut bytes = [0; 8];
+        std::fs::File::open("/dev/urandom")?.read_exact(&mut bytes)?;
+        fastrand::seed(u64::from_le_bytes(bytes));
+        Ok(())
+    }
+
+    // `io::ErrorKind::Unsupported` was stabilized in Rust 1.53. Our current
+    // MSRV is 1.43.
+    #[cfg(not(unix))]
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Other,
+        "no entropy source on this platform",
+    ))
 }
 
 macro_rules! define_ext {
-    ($(
-        $(#[$meta:meta])*
-        fn $name:ident(&mut self, $($argname:ident:$argty:ty),*) -> $ret:ty => $imp:path;
-    )*) => {
+    (
+        $(
+            $(#[$meta:meta])*
+            fn $name:ident $(<$($lt:lifetime),* $(,)? $($gen:ident $(: $bound:path)?),*>)?
+                (&mut self $(, $argname:ident: $argty:ty)*) $(-> $ret:ty)? => $imp:path;
+        )*
+        $(
+            // Methods which have no freestanding counterpart, e.g. because
+            // they refer to `Self`.
+            rng_only {
+                $(
+                    $(#[$lmeta:meta])*
+                    fn $lname:ident $(<$($llt:lifetime),* $(,)? $($lgen:ident $(: $lbound:path)?),*>)?
+                        (&mut self $(, $largname:ident: $largty:ty)*) $(-> $lret:ty)? => $limp:path;
+                )*
+            }
+        )?
+    ) => {
         /// Extra methods for [`fastrand::Rng`].
         pub trait RngExt: __private::Sealed {
             $(
             $(#[$meta])*
-            fn $name(&mut self, $($argname: $argty),*) -> $ret;
+            fn $name $(<$($lt,)* $($gen $(: $bound)?),*>)? (&mut self $(, $argname: $argty)*) $(-> $ret)?;
             )*
+            $($(
+            $(#[$lmeta])*
+            fn $lname $(<$($llt,)* $($lgen $(: $lbound)?),*>)? (&mut self $(, $largname: $largty)*) $(-> $lret)?;
+            )*)?
         }
 
         impl RngExt for Rng {
             $(
             $(#[$meta])*
-            fn $name(&mut self, $($argname: $argty),*) -> $ret {
-                $imp(self, $($argname),*)
+            fn $name $(<$($lt,)* $($gen $(: $bound)?),*>)? (&mut self $(, $argname: $argty)*) $(-> $ret)? {
+                $imp(self $(, $argname)*)
             }
             )*
+            $($(
+            $(#[$lmeta])*
+            fn $lname $(<$($llt,)* $($lgen $(: $lbound)?),*>)? (&mut self $(, $largname: $largty)*) $(-> $lret)? {
+                $limp(self $(, $largname)*)
+            }
+            )*)?
         }
 
         $(
         #[cfg(feature = "std")]
         impl GlobalRng {
             $(#[$meta])*
-            fn $name(&mut self, $($argname:$argty),*) -> $ret {
-                $imp(self, $($argname),*)
+            fn $name $(<$($lt,)* $($gen $(: $bound)?),*>)? (&mut self $(, $argname: $argty)*) $(-> $ret)? {
+                $imp(self $(, $argname)*)
             }
         }
         #[cfg(feature = "std")]
         $(#[$meta])*
-        pub fn $name($($argname:$argty),*) -> $ret {
-            GlobalRng::$name(&mut GlobalRng, $($argname),*)
+        pub fn $name $(<$($lt,)* $($gen $(: $bound)?),*>)? ($($argname: $argty),*) $(-> $ret)? {
+            GlobalRng::$name(&mut GlobalRng $(, $argname)*)
         }
         )*
     }
@@ -153,11 +363,81 @@ macro_rules! define_ext {
 
 define_ext! {
     /// Generate a 32-bit floating point number in the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range can't be sampled from, e.g. if it is reversed like
+    /// `5.0..1.0` or empty like `3.0..3.0`. This is checked in both debug and
+    /// release builds. See [`RangeError`] for details.
+    ///
+    /// Infinite bounds are treated like unbounded ones, so e.g.
+    /// `0.0..f32::INFINITY` generates finite non-negative numbers.
     fn f32_range(&mut self, range: impl RangeBounds<f32>) -> f32 => float_range::f32;
 
     /// Generate a 64-bit floating point number in the specified range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range can't be sampled from, e.g. if it is reversed like
+    /// `5.0..1.0` or empty like `3.0..3.0`. This is checked in both debug and
+    /// release builds. See [`RangeError`] for details.
+    ///
+    /// Infinite bounds are treated like unbounded ones, so e.g.
+    /// `0.0..f64::INFINITY` generates finite non-negative numbers.
     fn f64_range(&mut self, range: impl RangeBounds<f64>) -> f64 => float_range::f64;
 
+    /// Generate a 64-bit floating point number in the closed range
+    /// `[low, high]`, where both endpoints are reachable.
+    ///
+    /// The value is interpolated from one of 2^53 equally spaced points in
+    /// `[0, 1]`, so `low` and `high` are each generated with the same
+    /// probability as any other point. In contrast,
+    /// [`f64_range`](RngExt::f64_range) with `low..=high` stretches the range
+    /// to include `high`, which may then come up only extremely rarely. The
+    /// tradeoff is that rounding in the interpolation makes the spacing of
+    /// the generated values slightly uneven, and that no more than 2^53
+    /// distinct values are ever generated, however wide the range.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low > high` or if either bound is NaN.
+    fn f64_range_inclusive(&mut self, low: f64, high: f64) -> f64 => float_range::f64_inclusive;
+
+    /// Generate a 32-bit floating point number in the specified range, or
+    /// return an error if the range can't be sampled from.
+    fn try_f32_range(&mut self, range: impl RangeBounds<f32>) -> Result<f32, RangeError>
+        => float_range::try_f32;
+
+    /// Generate a 64-bit floating point number in the specified range, or
+    /// return an error if the range can't be sampled from.
+    fn try_f64_range(&mut self, range: impl RangeBounds<f64>) -> Result<f64, RangeError>
+        => float_range::try_f64;
+
+    /// Fill a slice with 32-bit floating point numbers in the `[0, 1)` range.
+    fn fill_f32(&mut self, buf: &mut [f32]) => fill::fill_f32;
+
+    /// Fill a slice with 64-bit floating point numbers in the `[0, 1)` range.
+    fn fill_f64(&mut self, buf: &mut [f64]) => fill::fill_f64;
+
+    /// Fill a slice with 64-bit floating point numbers in the specified range.
+    ///
+    /// This is faster than calling [`f64_range`](RngExt::f64_range) for every
+    /// element, because the range is resolved only once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range can't be sampled from. See [`RangeError`] for details.
+    fn fill_f64_range(&mut self, buf: &mut [f64], range: impl RangeBounds<f64>)
+        => fill::fill_f64_range;
+
+    /// Fill a slice with normally distributed 64-bit floating point numbers
+    /// with mean `mu` and standard deviation `sigma`.
+    ///
+    /// This is faster than calling [`f64_normal`](RngExt::f64_normal) for
+    /// every element, because each Box-Muller draw fills two elements.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn fill_f64_normal(&mut self, buf: &mut [f64], mu: f64, sigma: f64) => fill::fill_f64_normal;
+
     /// Generate a 32-bit floating point number in the normal distribution with
     /// mean mu and standard deviation sigma.
     #[cfg(any(feature = "std", feature = "libm"))]
@@ -168,6 +448,23 @@ define_ext! {
     #[cfg(any(feature = "std", feature = "libm"))]
     fn f64_normal(&mut self, mu: f64, sigma: f64) -> f64 => float_normal::f64;
 
+    /// Generate two independent 64-bit floating point numbers in the normal
+    /// distribution with mean mu and standard deviation sigma.
+    ///
+    /// Both values come from the same Box-Muller draw, so this is faster than
+    /// calling [`f64_normal`](RngExt::f64_normal) twice.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_normal_pair(&mut self, mu: f64, sigma: f64) -> (f64, f64) => float_normal::f64_pair;
+
+    /// Generate a 64-bit floating point number in the normal distribution with
+    /// mean mu and standard deviation sigma using the Ziggurat algorithm.
+    ///
+    /// This is usually faster than [`f64_normal`](RngExt::f64_normal), because
+    /// it only needs `exp` or `ln` for the rare values near the edges of its
+    /// precomputed layers or in the tail.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_normal_ziggurat(&mut self, mu: f64, sigma: f64) -> f64 => float_normal::f64_ziggurat;
+
     /// Generate a 32-bit floating point number in the normal distribution with
     /// mean mu and standard deviation sigma using an approximation algorithm.
     fn f32_normal_approx(&mut self, mu: f32, sigma: f32) -> f32 => float_normal::f32_approx;
@@ -175,6 +472,1009 @@ define_ext! {
     /// Generate a 64-bit floating point number in the normal distribution with
     /// mean mu and standard deviation sigma using an approximation algorithm.
     fn f64_normal_approx(&mut self, mu: f64, sigma: f64) -> f64 => float_normal::f64_approx;
+
+    /// Generate a 32-bit floating point number in the log-normal distribution,
+    /// whose logarithm is normally distributed with mean mu and standard
+    /// deviation sigma.
+    ///
+    /// The result is always positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_lognormal(&mut self, mu: f32, sigma: f32) -> f32 => float_normal::f32_lognormal;
+
+    /// Generate a 64-bit floating point number in the log-normal distribution,
+    /// whose logarithm is normally distributed with mean mu and standard
+    /// deviation sigma.
+    ///
+    /// The result is always positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_lognormal(&mut self, mu: f64, sigma: f64) -> f64 => float_normal::f64_lognormal;
+
+    /// Generate a 32-bit floating point number from the exponential
+    /// distribution with rate `lambda`.
+    ///
+    /// Returns positive infinity if `lambda` is not positive. With debug
+    /// assertions enabled, panics instead.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_exponential(&mut self, lambda: f32) -> f32 => float_exponential::f32;
+
+    /// Generate a 64-bit floating point number from the exponential
+    /// distribution with rate `lambda`.
+    ///
+    /// Returns positive infinity if `lambda` is not positive. With debug
+    /// assertions enabled, panics instead.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_exponential(&mut self, lambda: f64) -> f64 => float_exponential::f64;
+
+    /// Generate a number from the Poisson distribution with mean `lambda`.
+    ///
+    /// For `lambda >= 30`, a rounded normal approximation is used.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lambda` is negative or not finite.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn u64_poisson(&mut self, lambda: f64) -> u64 => poisson::u64_poisson;
+
+    /// Generate the number of failed Bernoulli trials with success probability
+    /// `p` before the first success.
+    ///
+    /// Returns `u64::MAX` if `p` is not positive. With debug assertions
+    /// enabled, panics if `p` is not in `(0, 1]` instead.
+    fn u64_geometric(&mut self, p: f64) -> u64 => discrete::u64_geometric;
+
+    /// Generate the number of successes in `n` Bernoulli trials with success
+    /// probability `p`.
+    ///
+    /// For large `n`, a rounded normal approximation may be used.
+    ///
+    /// With debug assertions enabled, panics if `p` is not in `[0, 1]`.
+    fn u64_binomial(&mut self, n: u64, p: f64) -> u64 => discrete::u64_binomial;
+
+    /// Generate a rank in `1..=n` from the Zipf distribution with exponent `s`.
+    ///
+    /// The probability of rank `k` is proportional to `1 / k^s`, e.g. for
+    /// skewed access patterns. Sampling takes constant expected time
+    /// regardless of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero or if `s` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn u64_zipf(&mut self, n: u64, s: f64) -> u64 => discrete::u64_zipf;
+
+    /// Distribute `n` trials among outcomes with the given probabilities.
+    ///
+    /// The probabilities are relative, i.e. they don't need to sum to 1. The
+    /// returned counts always sum to `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probs` is empty. With debug assertions enabled, also panics
+    /// if any of `probs` is negative.
+    #[cfg(feature = "alloc")]
+    fn multinomial(&mut self, n: u64, probs: &[f64]) -> Vec<u64> => discrete::multinomial;
+
+    /// Generate a number from the negative binomial distribution, i.e. the
+    /// number of failures before the `r`-th success in Bernoulli trials with
+    /// success probability `p`.
+    ///
+    /// `r` doesn't need to be an integer, which makes this useful for modeling
+    /// overdispersed counts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `r` is not positive or if `p` is not in `(0, 1]`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn u64_negative_binomial(&mut self, r: f64, p: f64) -> u64 => discrete::u64_negative_binomial;
+
+    /// Generate a boolean that is `true` with probability `p`.
+    ///
+    /// Always returns `false` if `p <= 0` and `true` if `p >= 1`. With debug
+    /// assertions enabled, panics if `p` is NaN.
+    fn bool_with_prob(&mut self, p: f64) -> bool => bernoulli::bool_with_prob;
+
+    /// Generate a boolean that is `true` with probability
+    /// `numerator / denominator`, e.g. `chance(1, 3)` for "1 in 3".
+    ///
+    /// Unlike [`bool_with_prob`](RngExt::bool_with_prob), the probability is
+    /// exact because no floating point rounding is involved. With debug
+    /// assertions enabled, panics if `denominator` is zero or `numerator` is
+    /// greater than `denominator`.
+    fn chance(&mut self, numerator: u64, denominator: u64) -> bool => bernoulli::chance;
+
+    /// Generate a 32-bit floating point number from the gamma distribution
+    /// with the given shape and scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` or `scale` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_gamma(&mut self, shape: f32, scale: f32) -> f32 => float_gamma::f32_gamma;
+
+    /// Generate a 64-bit floating point number from the gamma distribution
+    /// with the given shape and scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` or `scale` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_gamma(&mut self, shape: f64, scale: f64) -> f64 => float_gamma::f64_gamma;
+
+    /// Generate a 32-bit floating point number from the chi-squared
+    /// distribution with `k` degrees of freedom, which doesn't need to be an
+    /// integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_chi_squared(&mut self, k: f32) -> f32 => float_gamma::f32_chi_squared;
+
+    /// Generate a 64-bit floating point number from the chi-squared
+    /// distribution with `k` degrees of freedom, which doesn't need to be an
+    /// integer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_chi_squared(&mut self, k: f64) -> f64 => float_gamma::f64_chi_squared;
+
+    /// Generate a 64-bit floating point number from Student's t distribution
+    /// with `nu` degrees of freedom.
+    ///
+    /// As `nu` grows, this approaches the standard normal distribution, which
+    /// is used for an infinite `nu`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `nu` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_students_t(&mut self, nu: f64) -> f64 => float_gamma::f64_students_t;
+
+    /// Generate a 32-bit floating point number from the Beta distribution with
+    /// the given shape parameters.
+    ///
+    /// The result is always in `[0, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` or `beta` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_beta(&mut self, alpha: f32, beta: f32) -> f32 => float_gamma::f32_beta;
+
+    /// Generate a 64-bit floating point number from the Beta distribution with
+    /// the given shape parameters.
+    ///
+    /// The result is always in `[0, 1]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` or `beta` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_beta(&mut self, alpha: f64, beta: f64) -> f64 => float_gamma::f64_beta;
+
+    /// Generate a 64-bit floating point number in the Beta-PERT distribution
+    /// with the given minimum, most likely and maximum values.
+    ///
+    /// `lambda` controls how peaked the distribution is around `mode`. The
+    /// conventional value is 4.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min <= mode <= max` does not hold, if `min == max` or if
+    /// `lambda` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_pert(&mut self, min: f64, mode: f64, max: f64, lambda: f64) -> f64
+        => float_gamma::f64_pert;
+
+    /// Select each element of the slice independently with probability
+    /// `keep_prob`, preserving the original order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `keep_prob` is not in `[0, 1]`.
+    #[cfg(feature = "alloc")]
+    fn random_subsequence<'a, T>(&mut self, slice: &'a [T], keep_prob: f64) -> Vec<&'a T>
+        => seq::random_subsequence;
+
+    /// Choose a random index with probability proportional to the exponential
+    /// of the given log weights.
+    ///
+    /// The weights don't need to be normalized. The log-sum-exp trick is used,
+    /// so very large or very small log weights are handled without overflow or
+    /// underflow.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `log_weights` is empty or if it contains no finite maximum
+    /// (e.g. all weights are negative infinity).
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn weighted_index_log(&mut self, log_weights: &[f64]) -> usize => weighted::weighted_index_log;
+
+    /// Generate a uniformly distributed rotation angle in radians in the
+    /// `[0, 2π)` range.
+    fn rotation_2d(&mut self) -> f64 => geometry::rotation_2d;
+
+    /// Generate a uniformly distributed 2D rotation matrix
+    /// `[[cos, -sin], [sin, cos]]`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn rotation_matrix_2x2(&mut self) -> [[f64; 2]; 2] => geometry::rotation_matrix_2x2;
+
+    /// Generate a random rotation by at most `max_angle` radians as a unit
+    /// quaternion `[w, x, y, z]`.
+    ///
+    /// The rotation axis is uniformly distributed and the angle is uniform in
+    /// `[0, max_angle]`, which is useful for jittering an existing orientation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_angle` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn small_rotation_quaternion(&mut self, max_angle: f64) -> [f64; 4]
+        => geometry::small_rotation_quaternion;
+
+    /// Generate a point uniformly distributed in the triangle with the given
+    /// vertices.
+    fn point_in_triangle(&mut self, a: (f64, f64), b: (f64, f64), c: (f64, f64)) -> (f64, f64)
+        => geometry::point_in_triangle;
+
+    /// Generate a unit vector uniformly distributed on the circle.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn unit_vector_2d(&mut self) -> (f64, f64) => geometry::unit_vector_2d;
+
+    /// Generate a unit vector uniformly distributed on the circle without
+    /// trigonometric functions.
+    ///
+    /// Unlike `unit_vector_2d`, this uses rejection sampling and is available
+    /// without `std` or `libm`.
+    fn unit_vector_2d_rejection(&mut self) -> (f64, f64) => geometry::unit_vector_2d_rejection;
+
+    /// Generate a 32-bit unit vector uniformly distributed on the sphere.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_unit_vector_3d(&mut self) -> (f32, f32, f32) => geometry::f32_unit_vector_3d;
+
+    /// Generate a unit vector uniformly distributed on the sphere.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn unit_vector_3d(&mut self) -> (f64, f64, f64) => geometry::unit_vector_3d;
+
+    /// Generate a 32-bit point uniformly distributed in the ball of the given
+    /// radius centered at the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_point_in_sphere(&mut self, radius: f32) -> (f32, f32, f32)
+        => geometry::f32_point_in_sphere;
+
+    /// Generate a point uniformly distributed in the ball of the given radius
+    /// centered at the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn point_in_sphere(&mut self, radius: f64) -> (f64, f64, f64) => geometry::point_in_sphere;
+
+    /// Generate a 32-bit point uniformly distributed in the disk of the given
+    /// radius centered at the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_point_in_disk(&mut self, radius: f32) -> (f32, f32) => geometry::f32_point_in_disk;
+
+    /// Generate a point uniformly distributed in the disk of the given radius
+    /// centered at the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radius` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn point_in_disk(&mut self, radius: f64) -> (f64, f64) => geometry::point_in_disk;
+
+    /// Fill the buffer with a uniformly random permutation of `0..buf.len()`.
+    fn fill_permutation(&mut self, buf: &mut [usize]) => seq::fill_permutation;
+
+    /// Choose one of the neighboring cells on a 2D grid uniformly and return
+    /// its `(dx, dy)` offset.
+    ///
+    /// If `diagonal` is `false`, only the 4 orthogonal neighbors are
+    /// considered. Otherwise, all 8 surrounding cells are.
+    fn grid_step(&mut self, diagonal: bool) -> (i8, i8) => geometry::grid_step;
+
+    /// Choose one of the neighboring cells on a 3D grid uniformly and return
+    /// its `(dx, dy, dz)` offset.
+    ///
+    /// If `diagonal` is `false`, only the 6 face neighbors are considered.
+    /// Otherwise, all 26 surrounding cells are.
+    fn grid_step_3d(&mut self, diagonal: bool) -> (i8, i8, i8) => geometry::grid_step_3d;
+
+    /// Generate 64 random bits to be converted into a 64-bit floating point
+    /// number by [`f64_from_bits`].
+    ///
+    /// This consumes exactly one step of the generator.
+    fn f64_bits(&mut self) -> u64 => float_bits::f64_bits;
+
+    /// Generate up to `count` random non-overlapping intervals within
+    /// `span`, sorted by their start.
+    ///
+    /// Each interval has a random length in `(0, max_len]`. If the intervals
+    /// don't fit into the span, fewer than `count` intervals are returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `span` is inverted or if `max_len` is not positive.
+    #[cfg(feature = "alloc")]
+    fn random_intervals(&mut self, span: (f64, f64), count: usize, max_len: f64) -> Vec<(f64, f64)>
+        => intervals::random_intervals;
+
+    /// Generate a 64-bit floating point number in the Beta distribution
+    /// parameterized by its mean and concentration.
+    ///
+    /// This is equivalent to the Beta distribution with
+    /// `alpha = mean * concentration` and `beta = (1 - mean) * concentration`.
+    /// Higher concentration means lower variance around the mean.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mean` is not in `(0, 1)` or if `concentration` is not
+    /// positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_beta_mean(&mut self, mean: f64, concentration: f64) -> f64 => float_gamma::f64_beta_mean;
+
+    /// Generate a vector from the Dirichlet distribution with the given
+    /// concentration parameters.
+    ///
+    /// The components are in `[0, 1]` and sum to 1, e.g. random probabilities
+    /// of categorical outcomes.
+    ///
+    /// # Panics
+    ///
+    /// With debug assertions enabled, panics if any of `alphas` is not
+    /// positive.
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn dirichlet(&mut self, alphas: &[f64]) -> Vec<f64> => float_gamma::dirichlet;
+
+    /// Shuffle the slice so that items near its front are more likely to stay
+    /// near the front.
+    ///
+    /// The item at index `i` gets the weight `decay^i` and the result is a
+    /// weighted random permutation. With `decay == 1`, this is a uniform
+    /// shuffle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `decay` is not in `(0, 1]`.
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn recency_weighted_order<T>(&mut self, items: &mut [T], decay: f64) => seq::recency_weighted_order;
+
+    /// Choose `k` items without replacement with probability proportional to
+    /// their weights, returning both the chosen items and the remaining ones.
+    ///
+    /// The chosen items are in the order they were drawn, while the remaining
+    /// items keep their original order. Items with a zero weight are only
+    /// chosen once all other items are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `items` and `weights` have different lengths, if `k` is
+    /// greater than the number of items, or if any weight is negative or not
+    /// finite.
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn sample_without_replacement_weighted_partial<'a, T>(
+        &mut self,
+        items: &'a [T],
+        weights: &[f64],
+        k: usize
+    ) -> (Vec<&'a T>, Vec<&'a T>) => seq::sample_without_replacement_weighted_partial;
+
+    /// Generate a random IPv4 address as its four octets.
+    fn ipv4(&mut self) -> [u8; 4] => net::ipv4;
+
+    /// Generate a random IPv6 address as its sixteen octets.
+    fn ipv6(&mut self) -> [u8; 16] => net::ipv6;
+
+    /// Generate a random IPv4 address.
+    #[cfg(feature = "std")]
+    fn ipv4_addr(&mut self) -> Ipv4Addr => net::ipv4_addr;
+
+    /// Generate a random IPv6 address.
+    #[cfg(feature = "std")]
+    fn ipv6_addr(&mut self) -> Ipv6Addr => net::ipv6_addr;
+
+    /// Generate a random IPv4 address in the subnet given by `network` and
+    /// `prefix_len`.
+    ///
+    /// Only the host bits, i.e. the lowest `32 - prefix_len` bits, are
+    /// randomized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix_len` is greater than 32.
+    #[cfg(feature = "std")]
+    fn ipv4_in_subnet(&mut self, network: Ipv4Addr, prefix_len: u8) -> Ipv4Addr
+        => net::ipv4_in_subnet;
+
+    /// Generate a random IPv6 address in the subnet given by `network` and
+    /// `prefix_len`.
+    ///
+    /// Only the host bits, i.e. the lowest `128 - prefix_len` bits, are
+    /// randomized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix_len` is greater than 128.
+    #[cfg(feature = "std")]
+    fn ipv6_in_subnet(&mut self, network: Ipv6Addr, prefix_len: u8) -> Ipv6Addr
+        => net::ipv6_in_subnet;
+
+    /// Generate a 64-bit floating point number in the normal distribution with
+    /// mean mu and standard deviation sigma, reflected off the `low` and `high`
+    /// bounds until it lies within `[low, high]`.
+    ///
+    /// Unlike clamping, reflection doesn't pile up values at the bounds, and
+    /// unlike truncation, it doesn't discard any probability mass.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low >= high`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_normal_reflected(&mut self, mu: f64, sigma: f64, low: f64, high: f64) -> f64
+        => float_normal::f64_reflected;
+
+    /// Generate a 64-bit floating point number in the skew-normal distribution
+    /// with the given location, scale and shape alpha.
+    ///
+    /// Positive alpha skews the distribution to the right, negative alpha to
+    /// the left. Alpha of 0 gives the normal distribution.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_skew_normal(&mut self, location: f64, scale: f64, alpha: f64) -> f64
+        => float_normal::f64_skew_normal;
+
+    /// Generate a 64-bit floating point number in the normal distribution with
+    /// mean mu and standard deviation sigma, truncated to `[low, high]`.
+    ///
+    /// This stays efficient even if the bounds lie far in a tail of the
+    /// distribution.
+    ///
+    /// With debug assertions enabled, panics if `low >= high` or if `sigma` is
+    /// not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_truncated_normal(&mut self, mu: f64, sigma: f64, low: f64, high: f64) -> f64
+        => float_normal::f64_truncated;
+
+    /// Roll dice in the standard dice notation, e.g. `"3d6+2"` or `"d20"`.
+    ///
+    /// The notation is `[count]d<sides>[(+|-)modifier]`. The count defaults to
+    /// 1. Returns the sum of the rolls and the modifier, or a [`DiceError`] if
+    /// the notation is malformed or rolls more than
+    /// [`DiceError::MAX_DICE`] dice.
+    fn roll_dice(&mut self, notation: &str) -> Result<i64, DiceError> => dice::roll_dice;
+
+    /// Generate a random (version 4) UUID as raw bytes.
+    ///
+    /// The version and RFC 4122 variant bits are set, all other bits are
+    /// random.
+    fn uuid_v4(&mut self) -> [u8; 16] => uuid::uuid_v4;
+
+    /// Generate a random (version 4) UUID in the hyphenated lowercase hex
+    /// form, e.g. `"5b8b4a8e-2f1c-4e7d-9a3b-0c6d1e2f3a4b"`.
+    #[cfg(feature = "alloc")]
+    fn uuid_v4_string(&mut self) -> String => uuid::uuid_v4_string;
+
+    /// Generate a string of `len` characters, each chosen uniformly from
+    /// `charset`, e.g. DNA sequences from `['A', 'C', 'G', 'T']`.
+    ///
+    /// The charset may contain arbitrary Unicode characters, so the length of
+    /// the result in bytes may be greater than `len`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `charset` is empty.
+    #[cfg(feature = "alloc")]
+    fn string_from_chars(&mut self, len: usize, charset: &[char]) -> String => string::string_from_chars;
+
+    /// Generate a string of `len` random ASCII letters and digits.
+    #[cfg(feature = "alloc")]
+    fn alphanumeric_string(&mut self, len: usize) -> String => string::alphanumeric_string;
+
+    /// Append `len` random ASCII letters and digits to `out`.
+    ///
+    /// This allows reusing the allocation of an existing string.
+    #[cfg(feature = "alloc")]
+    fn push_alphanumeric(&mut self, out: &mut String, len: usize) => string::push_alphanumeric;
+
+    /// Generate a 64-bit floating point number in the specified range, but
+    /// outside of the open interval `(avoid - radius, avoid + radius)`.
+    ///
+    /// The value is sampled uniformly from the parts of the range that remain
+    /// after removing the interval.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `avoid` or `radius` is NaN, if `radius` is negative, if the
+    /// interval covers the whole range or if the range can't be sampled from.
+    fn f64_range_avoiding(&mut self, range: impl RangeBounds<f64>, avoid: f64, radius: f64) -> f64
+        => float_range::f64_avoiding;
+
+    /// Generate a uniformly distributed point inside a simple polygon.
+    ///
+    /// The vertices may be given in either orientation. The polygon doesn't
+    /// need to be convex, but its edges must not intersect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are fewer than 3 vertices or if the polygon has no
+    /// area.
+    #[cfg(feature = "alloc")]
+    fn point_in_polygon(&mut self, vertices: &[(f64, f64)]) -> (f64, f64) => geometry::point_in_polygon;
+
+    /// Generate a 64-bit floating point number in the standard exponential
+    /// distribution, i.e. with rate 1.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_standard_exponential(&mut self) -> f64 => float_dist::f64_standard_exponential;
+
+    /// Generate a [`Duration`] uniformly distributed in the specified range
+    /// with nanosecond precision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    fn duration_range(&mut self, range: impl RangeBounds<Duration>) -> Duration => time::duration_range;
+
+    /// Generate a retry delay using exponential backoff with full jitter.
+    ///
+    /// The delay is uniformly distributed in `[0, min(cap, base * 2^attempt)]`.
+    fn backoff_jitter(&mut self, base: Duration, cap: Duration, attempt: u32) -> Duration
+        => time::backoff_jitter;
+
+    /// Generate an exponentially distributed [`Duration`] with the given mean.
+    ///
+    /// This is useful for simulating delays between events that arrive as a
+    /// Poisson process. The result saturates at the largest representable
+    /// duration.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mean` is zero.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn exponential_duration(&mut self, mean: Duration) -> Duration => float_dist::exponential_duration;
+
+    /// Generate a 64-bit floating point number in the standard Cauchy
+    /// distribution, i.e. with median 0 and scale 1.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_standard_cauchy(&mut self) -> f64 => float_dist::f64_standard_cauchy;
+
+    /// Generate a 32-bit floating point number in the Cauchy distribution with
+    /// the given median and scale.
+    ///
+    /// Half of the values fall within `median ± scale`. The distribution has
+    /// no finite mean.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_cauchy(&mut self, median: f32, scale: f32) -> f32 => float_dist::f32_cauchy;
+
+    /// Generate a 64-bit floating point number in the Cauchy distribution with
+    /// the given median and scale.
+    ///
+    /// Half of the values fall within `median ± scale`. The distribution has
+    /// no finite mean.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_cauchy(&mut self, median: f64, scale: f64) -> f64 => float_dist::f64_cauchy;
+
+    /// Generate a 64-bit floating point number in the Pareto distribution with
+    /// the given scale, which is also the minimum value, and shape.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` or `shape` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_pareto(&mut self, scale: f64, shape: f64) -> f64 => float_dist::f64_pareto;
+
+    /// Generate a 32-bit floating point number in the Laplace distribution
+    /// with location mu and scale b.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_laplace(&mut self, mu: f32, b: f32) -> f32 => float_dist::f32_laplace;
+
+    /// Generate a 64-bit floating point number in the Laplace distribution
+    /// with location mu and scale b.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_laplace(&mut self, mu: f64, b: f64) -> f64 => float_dist::f64_laplace;
+
+    /// Generate a 64-bit floating point number in the Gumbel distribution with
+    /// the given location and scale.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_gumbel(&mut self, loc: f64, scale: f64) -> f64 => float_dist::f64_gumbel;
+
+    /// Generate a 32-bit floating point number in the Rayleigh distribution
+    /// with scale sigma, i.e. the length of a 2D vector whose components are
+    /// normally distributed with mean 0 and standard deviation sigma.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sigma` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_rayleigh(&mut self, sigma: f32) -> f32 => float_dist::f32_rayleigh;
+
+    /// Generate a 64-bit floating point number in the Rayleigh distribution
+    /// with scale sigma, i.e. the length of a 2D vector whose components are
+    /// normally distributed with mean 0 and standard deviation sigma.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `sigma` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_rayleigh(&mut self, sigma: f64) -> f64 => float_dist::f64_rayleigh;
+
+    /// Generate a 32-bit floating point number in the Weibull distribution with
+    /// the given shape and scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` or `scale` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_weibull(&mut self, shape: f32, scale: f32) -> f32 => float_dist::f32_weibull;
+
+    /// Generate a 64-bit floating point number in the Weibull distribution with
+    /// the given shape and scale.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shape` or `scale` is not positive.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_weibull(&mut self, shape: f64, scale: f64) -> f64 => float_dist::f64_weibull;
+
+    /// Generate an angle in the von Mises (circular normal) distribution with
+    /// mean direction mu and concentration kappa.
+    ///
+    /// The angle is in the `(-π, π]` range. Larger kappa concentrates the
+    /// angles around mu, kappa of 0 gives uniformly distributed angles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `kappa` is negative.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_von_mises(&mut self, mu: f64, kappa: f64) -> f64 => float_dist::f64_von_mises;
+
+    /// Generate a 64-bit floating point number in the standard logistic
+    /// distribution, i.e. with mean 0 and scale 1.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_standard_logistic(&mut self) -> f64 => float_dist::f64_standard_logistic;
+
+    /// Generate a 32-bit floating point number in the logistic distribution
+    /// with the given location and scale.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f32_logistic(&mut self, location: f32, scale: f32) -> f32 => float_dist::f32_logistic;
+
+    /// Generate a 64-bit floating point number in the logistic distribution
+    /// with the given location and scale.
+    ///
+    /// The distribution is symmetric around the location and has variance
+    /// `π² / 3 * scale²`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_logistic(&mut self, location: f64, scale: f64) -> f64 => float_dist::f64_logistic;
+
+    /// Generate the event times of a non-homogeneous Poisson process on
+    /// `[0, duration)` whose rate at time `t` is `rate_fn(t)`.
+    ///
+    /// `max_rate` must be an upper bound of `rate_fn` over the whole window.
+    /// The returned times are in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `duration` or `max_rate` is not positive. With debug
+    /// assertions enabled, also panics if `rate_fn` exceeds `max_rate`.
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn nonhomogeneous_poisson(
+        &mut self,
+        duration: f64,
+        max_rate: f64,
+        rate_fn: impl Fn(f64) -> f64
+    ) -> Vec<f64> => float_dist::nonhomogeneous_poisson;
+
+    /// Fill a `rows` by `cols` matrix stored in row-major order in `data` with
+    /// random entries.
+    ///
+    /// See [`MatrixDist`] for the available distributions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows * cols != data.len()`, if a symmetric positive definite
+    /// matrix is requested but `rows != cols` or if the uniform range is
+    /// invalid.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn fill_matrix(&mut self, data: &mut [f64], rows: usize, cols: usize, dist: MatrixDist)
+        => matrix::fill_matrix;
+
+    /// Choose a random index with probability proportional to the given
+    /// weights.
+    ///
+    /// Indices with a zero weight are never chosen. To choose from the same
+    /// weights many times, `AliasTable` is faster.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any weight is negative or not finite, or if all weights are
+    /// zero.
+    fn weighted_index(&mut self, weights: &[f64]) -> usize => weighted::weighted_index;
+
+    /// Choose a random index with probability proportional to the given
+    /// weights, using `default` for the weights that are `None`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any effective weight is negative or not finite, or if all
+    /// effective weights are zero.
+    fn weighted_index_with_default(&mut self, weights: &[Option<f64>], default: f64) -> usize
+        => weighted::weighted_index_with_default;
+
+    /// Choose a random index given the cumulative sums of the weights, in
+    /// `O(log n)` time.
+    ///
+    /// `cumulative[i]` must be the sum of the weights up to and including
+    /// index `i`, so the last element is the total weight.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cumulative` is empty or if the total weight is not positive
+    /// and finite. With debug assertions enabled, also panics if `cumulative`
+    /// is negative or decreasing anywhere.
+    fn weighted_index_from_cumulative(&mut self, cumulative: &[f64]) -> usize
+        => weighted::weighted_index_from_cumulative;
+
+    /// Generate a palette of `count` RGB colors whose hues are related
+    /// according to `scheme`.
+    ///
+    /// The base hue is uniformly random. Saturation and brightness vary
+    /// between colors but stay high enough for the hue to be recognizable.
+    #[cfg(feature = "alloc")]
+    fn color_palette(&mut self, count: usize, scheme: PaletteScheme) -> Vec<[u8; 3]>
+        => color::color_palette;
+
+    /// Generate a pair of 64-bit floating point numbers, each uniformly
+    /// distributed in `[0, 1]`, whose correlation is driven by `rho`.
+    ///
+    /// The pair is generated with a Gaussian copula: `rho` is the correlation
+    /// of the underlying standard normals. `rho = 1` gives identical values and
+    /// `rho = 0` gives independent values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rho` is not in `[-1, 1]`.
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_correlated_pair(&mut self, rho: f64) -> (f64, f64) => float_normal::f64_correlated_pair;
+
+    /// Generate a uniformly random derangement of `0..n`, i.e. a permutation
+    /// where no element stays in its place.
+    ///
+    /// Returns an empty vector for `n == 0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n == 1`, as no derangement of one element exists.
+    #[cfg(feature = "alloc")]
+    fn derangement(&mut self, n: usize) -> Vec<usize> => seq::derangement;
+
+    /// Choose a uniformly random element of the slice and return a mutable
+    /// reference to it.
+    ///
+    /// Returns `None` if the slice is empty. For shared references, use
+    /// [`fastrand::Rng::choice`].
+    fn choose_mut<'a, T>(&mut self, slice: &'a mut [T]) -> Option<&'a mut T> => seq::choose_mut;
+
+    /// Choose `n` distinct elements of the slice uniformly at random, in random
+    /// order.
+    ///
+    /// If `n` is at least the length of the slice, all elements are returned.
+    /// Unlike [`fastrand::Rng::choose_multiple`], whose result keeps the
+    /// elements in a partly fixed order, the order of the result is uniformly
+    /// random as well.
+    #[cfg(feature = "alloc")]
+    fn choose_multiple_shuffled<'a, T>(&mut self, slice: &'a [T], n: usize) -> Vec<&'a T>
+        => seq::choose_multiple_shuffled;
+
+    /// Move `k` uniformly chosen elements of the slice to its front in random
+    /// order, returning them and the remaining elements.
+    ///
+    /// This only takes `k` steps, unlike shuffling the whole slice. `k` is
+    /// clamped to the length of the slice.
+    fn partial_shuffle<'a, T>(&mut self, slice: &'a mut [T], k: usize) -> (&'a mut [T], &'a mut [T])
+        => seq::partial_shuffle;
+
+    /// Shuffle the order of consecutive `block_size`-element blocks of `slice`,
+    /// keeping the order of elements within each block.
+    ///
+    /// If the length of `slice` is not a multiple of `block_size`, the trailing
+    /// partial block stays at the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `block_size` is zero.
+    fn shuffle_blocks<T>(&mut self, slice: &mut [T], block_size: usize) => seq::shuffle_blocks;
+
+    /// Shuffle the slice so that items with the same key are more than
+    /// `min_gap` positions apart, like a playlist shuffle that avoids playing
+    /// the same artist twice in a row.
+    ///
+    /// # Panics
+    ///
+    /// Panics if no such order exists because some keys are too common.
+    #[cfg(feature = "alloc")]
+    fn constrained_shuffle<T, K: Eq>(&mut self, slice: &mut [T], key: impl Fn(&T) -> K, min_gap: usize)
+        => seq::constrained_shuffle;
+
+    /// Generate the `n - 1` edges of a uniformly random labeled tree on the
+    /// nodes `0..n`.
+    ///
+    /// Returns an empty vector for `n == 1`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n == 0`.
+    #[cfg(feature = "alloc")]
+    fn random_tree(&mut self, n: usize) -> Vec<(usize, usize)> => graph::random_tree;
+
+    /// Generate a 64-bit floating point number uniformly in `[low, high)`
+    /// together with its importance weight for the target density `pdf`.
+    ///
+    /// The weight is `pdf(x)` divided by the density of the uniform proposal,
+    /// i.e. `pdf(x) * (high - low)`. Averaging `f(x) * weight` over many
+    /// samples estimates the expected value of `f` under `pdf`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `low..high` can't be sampled from.
+    fn f64_range_importance(&mut self, low: f64, high: f64, pdf: impl Fn(f64) -> f64) -> (f64, f64)
+        => float_range::f64_importance;
+
+    /// Generate a random `u64` which differs from `previous` in at least
+    /// `min_bit_diff` bits.
+    ///
+    /// `min_bit_diff` randomly chosen bits of `previous` are flipped and the
+    /// remaining bits are random.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min_bit_diff > 64`.
+    fn u64_differing(&mut self, previous: u64, min_bit_diff: u32) -> u64 => bits::u64_differing;
+
+    /// Generate `n` roughly evenly spaced points on the unit sphere using the
+    /// Fibonacci spiral.
+    ///
+    /// `jitter` in `[0, 1]` randomly displaces each point within its part of
+    /// the spiral. With `jitter == 0`, the points only depend on `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `jitter` is not in `[0, 1]`.
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn fibonacci_sphere(&mut self, n: usize, jitter: f64) -> Vec<(f64, f64, f64)>
+        => geometry::fibonacci_sphere;
+
+    /// Generate a random undirected graph on the nodes `0..n` where each edge
+    /// is present with probability `p` and has a uniformly random weight from
+    /// `weight_range`.
+    ///
+    /// The edges are returned as `(a, b, weight)` with `a < b`. There are no
+    /// self-loops or duplicate edges.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `p` is not in `[0, 1]` or if `weight_range` is empty.
+    #[cfg(feature = "alloc")]
+    fn random_weighted_graph(&mut self, n: usize, p: f64, weight_range: Range<f64>) -> Vec<(usize, usize, f64)>
+        => graph::random_weighted_graph;
+
+    /// Generate a 64-bit floating point number in the specified range, biased
+    /// according to `shape`.
+    ///
+    /// This is useful when some bias is wanted but the exact distribution
+    /// doesn't matter. See [`Shape`] for the available shapes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range can't be sampled from (see [`RangeError`]) or if it
+    /// is not finite and `shape` is not [`Shape::Uniform`].
+    #[cfg(any(feature = "std", feature = "libm"))]
+    fn f64_range_shaped(&mut self, range: impl RangeBounds<f64>, shape: Shape) -> f64
+        => float_range::f64_shaped;
+
+    /// Generate a 64-bit floating point number in the triangular distribution
+    /// on `[low, high]` with its peak at `mode`.
+    ///
+    /// With debug assertions enabled, panics if `low <= mode <= high` does not
+    /// hold.
+    fn f64_triangular(&mut self, low: f64, mode: f64, high: f64) -> f64 => float_range::f64_triangular;
+
+    /// Generate `num_bytes` random bytes encoded as padded URL-safe base64.
+    ///
+    /// This is useful for generating strings that look like API keys or
+    /// tokens.
+    #[cfg(feature = "alloc")]
+    fn base64_string(&mut self, num_bytes: usize) -> String => string::base64_string;
+
+    /// Generate `num_bytes` random bytes encoded as padded base32 as defined in
+    /// RFC 4648.
+    #[cfg(feature = "alloc")]
+    fn base32_string(&mut self, num_bytes: usize) -> String => string::base32_string;
+
+    /// Generate `num_chars` random Unicode scalar values encoded as UTF-8.
+    ///
+    /// The characters are spread evenly over the 1 to 4 byte encodings, which
+    /// is useful for exercising the multibyte paths of text-processing code.
+    #[cfg(feature = "alloc")]
+    fn valid_utf8(&mut self, num_chars: usize) -> Vec<u8> => string::valid_utf8;
+
+    /// Generate a random JSON value for property-testing JSON consumers.
+    ///
+    /// Arrays and objects are nested at most `max_depth` levels deep, so
+    /// `max_depth = 0` only produces scalars (null, booleans, numbers and
+    /// strings).
+    #[cfg(feature = "serde_json")]
+    fn random_json(&mut self, max_depth: usize) -> serde_json_dep::Value => json::random_json;
+
+    rng_only {
+        /// Repeatedly generate values with `sample` until one of them passes
+        /// `accept`.
+        ///
+        /// Returns `None` if no value is accepted within `max_attempts`
+        /// attempts, so this never loops forever.
+        ///
+        /// ```
+        /// use fastrand::Rng;
+        /// use fastrand_contrib::RngExt;
+        ///
+        /// let mut rng = Rng::with_seed(0x1234);
+        /// let even = rng.sample_until(100, |rng| rng.u32(..), |x| x % 2 == 0);
+        /// assert!(even.unwrap() % 2 == 0);
+        /// ```
+        fn sample_until<T>(
+            &mut self,
+            max_attempts: u32,
+            sample: impl FnMut(&mut Self) -> T,
+            accept: impl Fn(&T) -> bool
+        ) -> Option<T> => rejection::sample_until;
+
+        /// Create an iterator that yields random 64-bit floating point numbers
+        /// in the `[0, 1)` range forever.
+        ///
+        /// ```
+        /// use fastrand::Rng;
+        /// use fastrand_contrib::RngExt;
+        ///
+        /// let mut rng = Rng::with_seed(0x1234);
+        /// let sum: f64 = rng.iter_f64().take(10).sum();
+        /// assert!(sum < 10.0);
+        /// ```
+        fn iter_f64(&mut self) -> RngIter<'_> => iter::iter_f64;
+
+        /// Create an iterator that yields the results of calling `f` with the
+        /// RNG forever.
+        ///
+        /// ```
+        /// use fastrand::Rng;
+        /// use fastrand_contrib::RngExt;
+        ///
+        /// let mut rng = Rng::with_seed(0x1234);
+        /// let dice: Vec<u8> = rng.iter_with(|rng| rng.u8(1..=6)).take(100).collect();
+        /// assert!(dice.iter().all(|&x| (1..=6).contains(&x)));
+        /// ```
+        fn iter_with<F: FnMut(&mut Rng) -> T, T>(&mut self, f: F) -> RngIterWith<'_, F>
+            => iter::iter_with;
+
+        /// Create an iterator that yields booleans in correlated runs forever.
+        ///
+        /// The first value is a fair coin flip. Every following value differs
+        /// from the previous one with probability `switch_prob`, so the runs
+        /// are `1 / switch_prob` long on average. A `switch_prob` of 0.5 gives
+        /// independent fair coin flips.
+        ///
+        /// With debug assertions enabled, panics if `switch_prob` is not in
+        /// `[0, 1]`.
+        fn bool_streak(&mut self, switch_prob: f64) -> BoolStreak<'_> => iter::bool_streak;
+    }
 }
 
 mod __private {
@@ -182,3 +1482,30 @@ mod __private {
     pub trait Sealed {}
     impl Sealed for fastrand::Rng {}
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_seeded_global_is_reproducible_and_restores_state() {
+        fastrand::seed(1);
+        let outer = fastrand::get_seed();
+
+        let first: Vec<f64> = with_seeded_global(42, || (0..8).map(|_| fastrand::f64()).collect());
+        let second: Vec<f64> = with_seeded_global(42, || (0..8).map(|_| fastrand::f64()).collect());
+        assert_eq!(first, second);
+
+        assert_eq!(fastrand::get_seed(), outer);
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn reseed_from_os_changes_sequence() {
+        reseed_from_os().unwrap();
+        let first: Vec<u64> = (0..4).map(|_| fastrand::u64(..)).collect();
+        reseed_from_os().unwrap();
+        let second: Vec<u64> = (0..4).map(|_| fastrand::u64(..)).collect();
+        assert_ne!(first, second);
+    }
+}
@@ -0,0 +1,149 @@
+use crate::{float_normal, float_range, BaseRng};
+
+/// The distribution of the entries of a matrix generated by
+/// [`RngExt::fill_matrix`].
+///
+/// [`RngExt::fill_matrix`]: crate::RngExt::fill_matrix
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MatrixDist {
+    /// Entries are uniformly distributed in `[low, high)`.
+    Uniform {
+        /// The lower bound.
+        low: f64,
+        /// The upper bound.
+        high: f64,
+    },
+    /// Entries are normally distributed with mean `mu` and standard deviation
+    /// `sigma`.
+    Normal {
+        /// The mean.
+        mu: f64,
+        /// The standard deviation.
+        sigma: f64,
+    },
+    /// The matrix is a random symmetric positive definite matrix. It must be
+    /// square.
+    SymmetricPositiveDefinite,
+}
+
+pub(super) fn fill_matrix(
+    rng: &mut impl BaseRng,
+    data: &mut [f64],
+    rows: usize,
+    cols: usize,
+    dist: MatrixDist,
+) {
+    assert_eq!(
+        rows.checked_mul(cols),
+        Some(data.len()),
+        "matrix dimensions don't match the data length"
+    );
+
+    match dist {
+        MatrixDist::Uniform { low, high } => {
+            for x in data.iter_mut() {
+                *x = float_range::f64(rng, low..high);
+            }
+        }
+        MatrixDist::Normal { mu, sigma } => {
+            for x in data.iter_mut() {
+                *x = float_normal::f64(rng, mu, sigma);
+            }
+        }
+        MatrixDist::SymmetricPositiveDefinite => {
+            assert_eq!(rows, cols, "positive definite matrix must be square");
+            fill_spd(rng, data, rows);
+        }
+    }
+}
+
+fn fill_spd(rng: &mut impl BaseRng, data: &mut [f64], n: usize) {
+    // Generate a random lower triangular matrix L with a positive diagonal. Then
+    // L * L^T is symmetric positive definite (it's the Cholesky decomposition).
+    for i in 0..n {
+        for j in 0..=i {
+            let x = float_normal::f64(rng, 0.0, 1.0);
+            data[i * n + j] = if i == j { 1.0 + x.abs() } else { x };
+        }
+    }
+
+    // Compute L * L^T in place. The result at (i, j) with j <= i only needs the
+    // entries of L in rows i and j up to column j. Going backwards through both
+    // rows and columns means that no entry of L is overwritten before its last
+    // use. The upper triangle doesn't hold any entries of L, so it can receive
+    // the mirrored values right away.
+    for i in (0..n).rev() {
+        for j in (0..=i).rev() {
+            let value: f64 = (0..=j).map(|k| data[i * n + k] * data[j * n + k]).sum();
+            data[i * n + j] = value;
+            data[j * n + i] = value;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn fill_matrix_spd_is_symmetric_positive_definite() {
+        let mut rng = Rng::with_seed(42);
+
+        let n = 6;
+        let mut data = [0.0; 36];
+        for _ in 0..100 {
+            fill_matrix(
+                &mut rng,
+                &mut data,
+                n,
+                n,
+                MatrixDist::SymmetricPositiveDefinite,
+            );
+
+            for i in 0..n {
+                assert!(data[i * n + i] > 0.0);
+                for j in 0..n {
+                    assert_eq!(data[i * n + j], data[j * n + i]);
+                }
+            }
+
+            // x^T M x must be positive for any non-zero x.
+            let mut x = [0.0; 6];
+            fill_matrix(
+                &mut rng,
+                &mut x,
+                n,
+                1,
+                MatrixDist::Uniform {
+                    low: -1.0,
+                    high: 1.0,
+                },
+            );
+            let quadratic_form: f64 = (0..n)
+                .flat_map(|i| (0..n).map(move |j| (i, j)))
+                .map(|(i, j)| x[i] * data[i * n + j] * x[j])
+                .sum();
+            assert!(quadratic_form > 0.0);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fill_matrix_checks_dimensions() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut data = [0.0; 6];
+        fill_matrix(
+            &mut rng,
+            &mut data,
+            2,
+            2,
+            MatrixDist::Normal {
+                mu: 0.0,
+                sigma: 1.0,
+            },
+        );
+    }
+}
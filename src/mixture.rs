@@ -0,0 +1,152 @@
+use alloc::vec::Vec;
+
+use crate::float_normal::{self, FloatMathExt};
+use crate::{weighted, BaseRng, Rng};
+
+/// A mixture of normal distributions.
+///
+/// Each component is a `(weight, mu, sigma)` tuple. A sample is drawn by first
+/// choosing a component with probability proportional to its weight and then
+/// sampling the normal distribution with mean `mu` and standard deviation
+/// `sigma`.
+///
+/// ```
+/// use fastrand::Rng;
+/// use fastrand_contrib::GaussianMixture;
+///
+/// let mixture = GaussianMixture::new(vec![(1.0, -5.0, 1.0), (3.0, 5.0, 1.0)]);
+///
+/// let mut rng = Rng::with_seed(0x1234);
+/// let x = mixture.sample(&mut rng);
+/// assert!(x.is_finite());
+/// ```
+#[derive(Debug, Clone)]
+pub struct GaussianMixture {
+    components: Vec<(f64, f64, f64)>,
+    total_weight: f64,
+}
+
+impl GaussianMixture {
+    /// Create a new mixture from `(weight, mu, sigma)` components.
+    ///
+    /// The weights don't need to be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no components, if any weight is negative or not
+    /// finite, if all weights are zero, or if any `sigma` is not positive and
+    /// finite.
+    pub fn new(components: Vec<(f64, f64, f64)>) -> Self {
+        assert!(!components.is_empty(), "mixture must have components");
+
+        let mut total_weight = 0.0;
+        for &(weight, _, sigma) in components.iter() {
+            assert!(
+                weight >= 0.0 && weight.is_finite(),
+                "mixture weights must be non-negative and finite"
+            );
+            assert!(
+                sigma > 0.0 && sigma.is_finite(),
+                "mixture standard deviations must be positive and finite"
+            );
+            total_weight += weight;
+        }
+        assert!(total_weight > 0.0, "mixture weights must not all be zero");
+
+        GaussianMixture {
+            components,
+            total_weight,
+        }
+    }
+
+    /// Get the `(weight, mu, sigma)` components of the mixture.
+    pub fn components(&self) -> &[(f64, f64, f64)] {
+        &self.components
+    }
+
+    /// Generate a 64-bit floating point number from the mixture.
+    pub fn sample(&self, rng: &mut Rng) -> f64 {
+        self.sample_impl(rng)
+    }
+
+    /// Evaluate the probability density function of the mixture at `x`.
+    pub fn pdf(&self, x: f64) -> f64 {
+        self.components
+            .iter()
+            .map(|&(weight, mu, sigma)| weight * normal_pdf(x, mu, sigma))
+            .sum::<f64>()
+            / self.total_weight
+    }
+
+    fn sample_impl(&self, rng: &mut impl BaseRng) -> f64 {
+        let index = weighted::weighted_index_impl(
+            rng,
+            self.components.iter().map(|&(weight, _, _)| weight),
+        );
+        let (_, mu, sigma) = self.components[index];
+        float_normal::f64(rng, mu, sigma)
+    }
+}
+
+fn normal_pdf(x: f64, mu: f64, sigma: f64) -> f64 {
+    // 1 / sqrt(2 * pi)
+    const FRAC_1_SQRT_2PI: f64 = 0.398_942_280_401_432_7;
+
+    let z = (x - mu) / sigma;
+    FRAC_1_SQRT_2PI / sigma * FloatMathExt::exp(-0.5 * z * z)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mixture_is_bimodal() {
+        let mut rng = Rng::with_seed(42);
+
+        let mixture = GaussianMixture::new(alloc::vec![(1.0, -5.0, 1.0), (1.0, 5.0, 1.0)]);
+
+        let total = 10000;
+        let mut left = 0;
+        let mut middle = 0;
+        for _ in 0..total {
+            let x = mixture.sample(&mut rng);
+            if x.abs() < 1.0 {
+                middle += 1;
+            } else if x < 0.0 {
+                left += 1;
+            }
+        }
+
+        let left = left as f64 / total as f64 * 100.0;
+        assert!((48.0..=52.0).contains(&left), "left mode sampled {}%", left);
+        assert!(middle < total / 100, "{} samples between the modes", middle);
+    }
+
+    #[test]
+    fn mixture_pdf_integrates_to_one() {
+        let mixture = GaussianMixture::new(alloc::vec![(1.0, -5.0, 1.0), (3.0, 2.0, 0.5)]);
+
+        let step = 0.001;
+        let integral: f64 = (-20000..20000)
+            .map(|i| mixture.pdf(i as f64 * step) * step)
+            .sum();
+        assert!((integral - 1.0).abs() < 1e-6, "integral is {}", integral);
+    }
+
+    #[test]
+    fn mixture_skips_zero_weight_components() {
+        let mut rng = Rng::with_seed(42);
+
+        let mixture = GaussianMixture::new(alloc::vec![(1.0, -5.0, 1.0), (0.0, 1e9, 1.0)]);
+        for _ in 0..10000 {
+            assert!(mixture.sample(&mut rng) < 1e6);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "mixture standard deviations must be positive and finite")]
+    fn mixture_rejects_zero_sigma() {
+        GaussianMixture::new(alloc::vec![(1.0, 0.0, 0.0)]);
+    }
+}
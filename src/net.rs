@@ -0,0 +1,76 @@
+#[cfg(feature = "std")]
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::BaseRng;
+
+pub(super) fn ipv4(rng: &mut impl BaseRng) -> [u8; 4] {
+    rng.u32().to_be_bytes()
+}
+
+pub(super) fn ipv6(rng: &mut impl BaseRng) -> [u8; 16] {
+    rng.u128().to_be_bytes()
+}
+
+#[cfg(feature = "std")]
+pub(super) fn ipv4_addr(rng: &mut impl BaseRng) -> Ipv4Addr {
+    Ipv4Addr::from(ipv4(rng))
+}
+
+#[cfg(feature = "std")]
+pub(super) fn ipv6_addr(rng: &mut impl BaseRng) -> Ipv6Addr {
+    Ipv6Addr::from(ipv6(rng))
+}
+
+#[cfg(feature = "std")]
+pub(super) fn ipv4_in_subnet(
+    rng: &mut impl BaseRng,
+    network: Ipv4Addr,
+    prefix_len: u8,
+) -> Ipv4Addr {
+    assert!(prefix_len <= 32, "IPv4 prefix length must be at most 32");
+
+    let mask = u32::MAX.checked_shl(32 - prefix_len as u32).unwrap_or(0);
+    let network = u32::from(network) & mask;
+    Ipv4Addr::from(network | (rng.u32() & !mask))
+}
+
+#[cfg(feature = "std")]
+pub(super) fn ipv6_in_subnet(
+    rng: &mut impl BaseRng,
+    network: Ipv6Addr,
+    prefix_len: u8,
+) -> Ipv6Addr {
+    assert!(prefix_len <= 128, "IPv6 prefix length must be at most 128");
+
+    let mask = u128::MAX.checked_shl(128 - prefix_len as u32).unwrap_or(0);
+    let network = u128::from(network) & mask;
+    Ipv6Addr::from(network | (rng.u128() & !mask))
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn ip_in_subnet_stays_in_network() {
+        let mut rng = Rng::with_seed(42);
+
+        let network = Ipv4Addr::new(192, 168, 12, 0);
+        for _ in 0..1000 {
+            let addr = ipv4_in_subnet(&mut rng, network, 22).octets();
+            assert_eq!(addr[..2], [192, 168]);
+            assert_eq!(addr[2] & 0b1111_1100, 12);
+        }
+        assert_eq!(ipv4_in_subnet(&mut rng, network, 32), network);
+        ipv4_in_subnet(&mut rng, network, 0);
+
+        let network = Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0);
+        for _ in 0..1000 {
+            let addr = ipv6_in_subnet(&mut rng, network, 32).segments();
+            assert_eq!(addr[..2], [0x2001, 0xdb8]);
+        }
+        assert_eq!(ipv6_in_subnet(&mut rng, network, 128), network);
+    }
+}
@@ -0,0 +1,71 @@
+use crate::discrete::saturating_u64;
+use crate::float_normal::{self, FloatMathExt};
+use crate::BaseRng;
+
+/// Above this mean, the normal approximation is used instead of Knuth's
+/// algorithm, whose running time grows linearly with the mean.
+const NORMAL_APPROX_THRESHOLD: f64 = 30.0;
+
+pub(super) fn u64_poisson(rng: &mut impl BaseRng, lambda: f64) -> u64 {
+    assert!(
+        lambda >= 0.0 && lambda.is_finite(),
+        "lambda must be non-negative and finite, but got {}",
+        lambda
+    );
+
+    if lambda < NORMAL_APPROX_THRESHOLD {
+        // Knuth's algorithm: count how many uniform numbers can be multiplied
+        // together before the product drops below `e^-lambda`.
+        let limit = FloatMathExt::exp(-lambda);
+        let mut count = 0;
+        let mut product = rng.f64();
+        while product > limit {
+            count += 1;
+            product *= rng.f64();
+        }
+        count
+    } else {
+        // Round to the nearest integer. Negative values saturate to zero.
+        let value = float_normal::f64(rng, lambda, FloatMathExt::sqrt(lambda));
+        saturating_u64(value + 0.5)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn poisson_mean_and_variance_match_lambda() {
+        let mut rng = Rng::with_seed(42);
+
+        for &lambda in [0.5, 4.0, 29.0, 100.0].iter() {
+            let total = 100_000;
+            let (mut sum, mut sum_sq) = (0.0, 0.0);
+            for _ in 0..total {
+                let x = u64_poisson(&mut rng, lambda) as f64;
+                sum += x;
+                sum_sq += x * x;
+            }
+            let mean = sum / total as f64;
+            let variance = sum_sq / total as f64 - mean * mean;
+
+            assert!(
+                (mean - lambda).abs() < 0.02 * lambda + 0.01,
+                "mean should be ~{}, but is {}",
+                lambda,
+                mean
+            );
+            assert!(
+                (variance - lambda).abs() < 0.05 * lambda + 0.01,
+                "variance should be ~{}, but is {}",
+                lambda,
+                variance
+            );
+        }
+
+        assert_eq!(u64_poisson(&mut rng, 0.0), 0);
+    }
+}
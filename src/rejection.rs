@@ -0,0 +1,43 @@
+pub(super) fn sample_until<R, T>(
+    rng: &mut R,
+    max_attempts: u32,
+    mut sample: impl FnMut(&mut R) -> T,
+    accept: impl Fn(&T) -> bool,
+) -> Option<T> {
+    for _ in 0..max_attempts {
+        let value = sample(rng);
+        if accept(&value) {
+            return Some(value);
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn sample_until_respects_max_attempts() {
+        let mut rng = Rng::with_seed(42);
+
+        let accepted = sample_until(&mut rng, 100, |rng| rng.u8(..), |&x| x < 128);
+        assert!(accepted.unwrap() < 128);
+
+        let mut attempts = 0;
+        let rejected = sample_until(
+            &mut rng,
+            100,
+            |rng| {
+                attempts += 1;
+                rng.u8(..)
+            },
+            |_| false,
+        );
+        assert_eq!(rejected, None);
+        assert_eq!(attempts, 100);
+    }
+}
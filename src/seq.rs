@@ -0,0 +1,650 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use crate::float_normal::FloatMathExt;
+use crate::BaseRng;
+
+#[cfg(feature = "alloc")]
+pub(super) fn random_subsequence<'a, T>(
+    rng: &mut impl BaseRng,
+    slice: &'a [T],
+    keep_prob: f64,
+) -> Vec<&'a T> {
+    assert!(
+        (0.0..=1.0).contains(&keep_prob),
+        "keep probability must be in [0, 1]"
+    );
+
+    // Walking the slice front to back keeps the original order.
+    slice.iter().filter(|_| rng.f64() < keep_prob).collect()
+}
+
+pub(super) fn choose_mut<'a, T>(rng: &mut impl BaseRng, slice: &'a mut [T]) -> Option<&'a mut T> {
+    if slice.is_empty() {
+        None
+    } else {
+        let i = rng.usize(..slice.len());
+        Some(&mut slice[i])
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn choose_multiple_shuffled<'a, T>(
+    rng: &mut impl BaseRng,
+    slice: &'a [T],
+    n: usize,
+) -> Vec<&'a T> {
+    let mut chosen: Vec<&T> = slice.iter().collect();
+    let n = partial_shuffle(rng, &mut chosen, n).0.len();
+    chosen.truncate(n);
+    chosen
+}
+
+pub(super) fn fill_permutation(rng: &mut impl BaseRng, buf: &mut [usize]) {
+    for (i, x) in buf.iter_mut().enumerate() {
+        *x = i;
+    }
+    shuffle_impl(rng, buf);
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub(super) fn recency_weighted_order<T>(rng: &mut impl BaseRng, items: &mut [T], decay: f64) {
+    assert!(decay > 0.0 && decay <= 1.0, "decay must be in (0, 1]");
+
+    // Weighted random permutation using exponential keys: item `i` with weight
+    // `w_i` gets the key `E_i / w_i` where `E_i` is exponentially distributed,
+    // and the items are ordered by ascending keys. The keys are compared in the
+    // log domain so that tiny weights `decay^i` don't underflow.
+    let ln_decay = FloatMathExt::ln(decay);
+    let mut keys: Vec<(f64, usize)> = (0..items.len())
+        .map(|i| {
+            let exp = -FloatMathExt::ln(1.0 - rng.f64());
+            (FloatMathExt::ln(exp) - i as f64 * ln_decay, i)
+        })
+        .collect();
+    keys.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let order: Vec<usize> = keys.iter().map(|&(_, i)| i).collect();
+    apply_permutation(items, &order);
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn constrained_shuffle<T, K: Eq>(
+    rng: &mut impl BaseRng,
+    slice: &mut [T],
+    key: impl Fn(&T) -> K,
+    min_gap: usize,
+) {
+    // Group the indices by key, in random order within each group.
+    let mut groups: Vec<(K, Vec<usize>)> = Vec::new();
+    for (i, item) in slice.iter().enumerate() {
+        let k = key(item);
+        match groups.iter_mut().find(|(g, _)| *g == k) {
+            Some((_, indices)) => indices.push(i),
+            None => groups.push((k, alloc::vec![i])),
+        }
+    }
+    for (_, indices) in groups.iter_mut() {
+        shuffle_impl(rng, indices);
+    }
+
+    // With `m` the largest group size and `p` the number of groups of that
+    // size, the largest groups need `(m - 1) * (min_gap + 1) + p` positions.
+    let counts: Vec<usize> = groups.iter().map(|(_, indices)| indices.len()).collect();
+    let max = counts.iter().copied().max().unwrap_or(0);
+    let num_max = counts.iter().filter(|&&c| c == max).count();
+    assert!(
+        max == 0 || (max - 1) * (min_gap + 1) + num_max <= slice.len(),
+        "no shuffle keeps items with the same key {} positions apart",
+        min_gap
+    );
+
+    // Place a random eligible group at each position, weighted by how many of
+    // its items are left. This can run into a dead end, so after a few
+    // attempts fall back to always placing a group with the most items left,
+    // which succeeds whenever the check above passes.
+    let mut sequence = Vec::with_capacity(slice.len());
+    let attempts = 8;
+    for attempt in 0..=attempts {
+        if place_groups(rng, &counts, min_gap, attempt == attempts, &mut sequence) {
+            break;
+        }
+        assert!(
+            attempt < attempts,
+            "failed to satisfy the key gap constraint"
+        );
+    }
+
+    let mut next = alloc::vec![0; groups.len()];
+    let order: Vec<usize> = sequence
+        .iter()
+        .map(|&g| {
+            next[g] += 1;
+            groups[g].1[next[g] - 1]
+        })
+        .collect();
+    apply_permutation(slice, &order);
+}
+
+/// Fill `sequence` with the group to place at each position so that equal
+/// groups are more than `min_gap` positions apart. Returns `false` if no group
+/// is eligible at some position.
+#[cfg(feature = "alloc")]
+fn place_groups(
+    rng: &mut impl BaseRng,
+    counts: &[usize],
+    min_gap: usize,
+    greedy: bool,
+    sequence: &mut Vec<usize>,
+) -> bool {
+    let mut remaining = counts.to_vec();
+    let mut last: Vec<Option<usize>> = alloc::vec![None; counts.len()];
+    let len: usize = counts.iter().sum();
+    sequence.clear();
+
+    for pos in 0..len {
+        let eligible =
+            |g: usize| remaining[g] > 0 && last[g].map_or(true, |l: usize| pos - l > min_gap);
+
+        let chosen = if greedy {
+            // Break ties between the largest groups uniformly at random.
+            let mut chosen = None;
+            let mut ties = 0;
+            for g in (0..counts.len()).filter(|&g| eligible(g)) {
+                let best = chosen.map_or(0, |c: usize| remaining[c]);
+                if remaining[g] > best {
+                    chosen = Some(g);
+                    ties = 1;
+                } else if remaining[g] == best {
+                    ties += 1;
+                    if rng.usize(..ties) == 0 {
+                        chosen = Some(g);
+                    }
+                }
+            }
+            chosen
+        } else {
+            let total: usize = (0..counts.len())
+                .filter(|&g| eligible(g))
+                .map(|g| remaining[g])
+                .sum();
+            if total == 0 {
+                None
+            } else {
+                let mut target = rng.usize(..total);
+                (0..counts.len()).filter(|&g| eligible(g)).find(|&g| {
+                    if target < remaining[g] {
+                        true
+                    } else {
+                        target -= remaining[g];
+                        false
+                    }
+                })
+            }
+        };
+
+        match chosen {
+            Some(g) => {
+                remaining[g] -= 1;
+                last[g] = Some(pos);
+                sequence.push(g);
+            }
+            None => return false,
+        }
+    }
+
+    true
+}
+
+/// Reorder `items` so that the item at index `order[i]` moves to index `i`.
+#[cfg(feature = "alloc")]
+fn apply_permutation<T>(items: &mut [T], order: &[usize]) {
+    // Elements before `i` are already in their final position, so if the
+    // wanted element was swapped away earlier, follow the chain of swaps to
+    // find where it is now.
+    for i in 0..items.len() {
+        let mut j = order[i];
+        while j < i {
+            j = order[j];
+        }
+        items.swap(i, j);
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub(super) fn sample_without_replacement_weighted_partial<'a, T>(
+    rng: &mut impl BaseRng,
+    items: &'a [T],
+    weights: &[f64],
+    k: usize,
+) -> (Vec<&'a T>, Vec<&'a T>) {
+    assert_eq!(
+        items.len(),
+        weights.len(),
+        "items and weights must have the same length"
+    );
+    assert!(k <= items.len(), "cannot choose more items than available");
+
+    // Each item gets the key `E_i / w_i` where `E_i` is exponentially
+    // distributed, and the `k` items with the smallest keys win. Items with a
+    // zero weight get an infinite key and only win if there are not enough
+    // other items.
+    let mut keys: Vec<(f64, usize)> = weights
+        .iter()
+        .enumerate()
+        .map(|(i, &weight)| {
+            assert!(
+                weight >= 0.0 && weight.is_finite(),
+                "weights must be non-negative and finite, but got {}",
+                weight
+            );
+            let key = if weight > 0.0 {
+                -FloatMathExt::ln(1.0 - rng.f64()) / weight
+            } else {
+                f64::INFINITY
+            };
+            (key, i)
+        })
+        .collect();
+    keys.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+
+    let mut chosen = alloc::vec![false; items.len()];
+    let winners = keys[..k]
+        .iter()
+        .map(|&(_, i)| {
+            chosen[i] = true;
+            &items[i]
+        })
+        .collect();
+    let rest = items
+        .iter()
+        .zip(chosen)
+        .filter(|&(_, chosen)| !chosen)
+        .map(|(item, _)| item)
+        .collect();
+
+    (winners, rest)
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn derangement(rng: &mut impl BaseRng, n: usize) -> Vec<usize> {
+    assert!(n != 1, "there is no derangement of a single element");
+
+    // ratios[u] = D(u - 1) / D(u), where D(u) is the number of derangements of
+    // u elements. Working with ratios instead of D(u) avoids overflow. They
+    // follow from D(u) = (u - 1) * (D(u - 1) + D(u - 2)).
+    let mut ratios = alloc::vec![0.0; n + 1];
+    for u in 3..=n {
+        ratios[u] = 1.0 / ((u - 1) as f64 * (1.0 + ratios[u - 1]));
+    }
+
+    // Martinez, Panholzer and Prodinger, "Generating random derangements":
+    // https://epubs.siam.org/doi/pdf/10.1137/1.9781611972986.7
+    let mut result: Vec<usize> = (0..n).collect();
+    let mut marked = alloc::vec![false; n];
+    let mut i = n;
+    let mut u = n;
+    while u >= 2 {
+        i -= 1;
+        if marked[i] {
+            continue;
+        }
+
+        let j = loop {
+            let j = rng.usize(..i);
+            if !marked[j] {
+                break j;
+            }
+        };
+        result.swap(i, j);
+
+        // Probability that `i` and `j` form a 2-cycle, which is
+        // (u - 1) * D(u - 2) / D(u).
+        let close_cycle = if u == 2 {
+            1.0
+        } else {
+            (u - 1) as f64 * ratios[u] * ratios[u - 1]
+        };
+        if rng.f64() < close_cycle {
+            marked[j] = true;
+            u -= 1;
+        }
+        u -= 1;
+    }
+
+    result
+}
+
+pub(super) fn shuffle_blocks<T>(rng: &mut impl BaseRng, slice: &mut [T], block_size: usize) {
+    assert!(block_size != 0, "block size must not be zero");
+
+    // Fisher-Yates over the full blocks. A trailing partial block is left at
+    // the end, since swapping it with a full block would change its size.
+    let blocks = slice.len() / block_size;
+    for i in (1..blocks).rev() {
+        let j = rng.usize(..=i);
+        if i != j {
+            let (head, tail) = slice.split_at_mut(i * block_size);
+            head[j * block_size..(j + 1) * block_size].swap_with_slice(&mut tail[..block_size]);
+        }
+    }
+}
+
+pub(super) fn partial_shuffle<'a, T>(
+    rng: &mut impl BaseRng,
+    slice: &'a mut [T],
+    k: usize,
+) -> (&'a mut [T], &'a mut [T]) {
+    // Fisher-Yates front to back, stopping after `k` swaps.
+    let k = k.min(slice.len());
+    for i in 0..k {
+        slice.swap(i, rng.usize(i..slice.len()));
+    }
+    slice.split_at_mut(k)
+}
+
+fn shuffle_impl<T>(rng: &mut impl BaseRng, slice: &mut [T]) {
+    // https://en.wikipedia.org/wiki/Fisher%E2%80%93Yates_shuffle
+    for i in (1..slice.len()).rev() {
+        slice.swap(i, rng.usize(..=i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn random_subsequence_preserves_order() {
+        let mut rng = Rng::with_seed(42);
+
+        let input: Vec<usize> = (0..100).collect();
+        let total = 1000;
+        let mut kept = 0;
+        for _ in 0..total {
+            let sub = random_subsequence(&mut rng, &input, 0.3);
+            assert!(sub.windows(2).all(|w| w[0] < w[1]));
+            kept += sub.len();
+        }
+
+        let mean = kept as f64 / total as f64;
+        assert!(
+            (28.0..=32.0).contains(&mean),
+            "expected ~30 elements kept on average, got {}",
+            mean
+        );
+    }
+
+    #[test]
+    fn fill_permutation_is_permutation() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut buf = [0; 32];
+        for _ in 0..100 {
+            fill_permutation(&mut rng, &mut buf);
+
+            let mut seen = [false; 32];
+            for &x in buf.iter() {
+                assert!(!seen[x]);
+                seen[x] = true;
+            }
+        }
+
+        let mut empty: [usize; 0] = [];
+        fill_permutation(&mut rng, &mut empty);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn recency_weighted_order_favors_early_items() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut position_sums = [0; 10];
+        for _ in 0..1000 {
+            let mut items: Vec<usize> = (0..10).collect();
+            recency_weighted_order(&mut rng, &mut items, 0.5);
+
+            let mut seen = [false; 10];
+            for (position, &item) in items.iter().enumerate() {
+                assert!(!seen[item]);
+                seen[item] = true;
+                position_sums[item] += position;
+            }
+        }
+
+        assert!(position_sums[0] < position_sums[4]);
+        assert!(position_sums[4] < position_sums[9]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn sample_without_replacement_weighted_partial_partitions_input() {
+        let mut rng = Rng::with_seed(42);
+
+        let items: Vec<usize> = (0..10).collect();
+        let weights = [1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 0.0, 20.0];
+        let mut nine_wins = 0;
+        for k in 0..=10 {
+            for _ in 0..100 {
+                let (winners, rest) =
+                    sample_without_replacement_weighted_partial(&mut rng, &items, &weights, k);
+                assert_eq!(winners.len(), k);
+                assert_eq!(rest.len(), 10 - k);
+
+                let mut seen = [false; 10];
+                for &&item in winners.iter().chain(rest.iter()) {
+                    assert!(!seen[item]);
+                    seen[item] = true;
+                }
+                assert!(rest.windows(2).all(|w| w[0] < w[1]));
+
+                // The zero-weight item only wins if every other item does.
+                assert_eq!(winners.contains(&&8), k == 10);
+                if k == 1 && *winners[0] == 9 {
+                    nine_wins += 1;
+                }
+            }
+        }
+        assert!(nine_wins > 60, "heavy item won {} of 100 times", nine_wins);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn constrained_shuffle_keeps_gap() {
+        let mut rng = Rng::with_seed(42);
+
+        // 4 items of key 0 need 3 * 3 + 1 = 10 positions with a gap of 2.
+        let mut firsts = [0; 12];
+        for _ in 0..1000 {
+            let keys = [0, 0, 0, 0, 1, 1, 1, 2, 2, 3, 3, 4];
+            let mut items: Vec<(usize, usize)> = keys.iter().copied().zip(0..).collect();
+            constrained_shuffle(&mut rng, &mut items, |&(k, _)| k, 2);
+
+            let mut seen = [false; 12];
+            for (i, &(k, id)) in items.iter().enumerate() {
+                assert!(!seen[id]);
+                seen[id] = true;
+                for &(other, _) in &items[i + 1..(i + 3).min(12)] {
+                    assert_ne!(k, other, "{:?}", items);
+                }
+            }
+            firsts[items[0].1] += 1;
+        }
+        assert!(firsts.iter().all(|&n| n > 0), "{:?}", firsts);
+
+        // A tight input only has the orders `abcabcabcab` with `a` and `b`
+        // swappable.
+        for _ in 0..100 {
+            let mut items = [0, 0, 0, 0, 1, 1, 1, 1, 2, 2, 2];
+            constrained_shuffle(&mut rng, &mut items, |&k| k, 2);
+            assert!(items
+                .windows(3)
+                .all(|w| w[0] != w[1] && w[0] != w[2] && w[1] != w[2]));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    #[should_panic(expected = "no shuffle keeps items with the same key")]
+    fn constrained_shuffle_rejects_dominant_key() {
+        let mut items = [0, 0, 0, 1, 2, 3];
+        constrained_shuffle(&mut Rng::with_seed(42), &mut items, |&k| k, 2);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn derangement_is_uniform_without_fixed_points() {
+        let mut rng = Rng::with_seed(42);
+
+        // There are 9 derangements of 4 elements.
+        let mut found: Vec<(Vec<usize>, usize)> = Vec::new();
+        let total = 9000;
+        for _ in 0..total {
+            let d = derangement(&mut rng, 4);
+            assert!(d.iter().enumerate().all(|(i, &x)| i != x));
+            match found.iter_mut().find(|(p, _)| *p == d) {
+                Some((_, count)) => *count += 1,
+                None => found.push((d, 1)),
+            }
+        }
+        assert_eq!(found.len(), 9);
+        for (_, count) in found {
+            assert!((850..=1150).contains(&count), "sampled {} times", count);
+        }
+
+        for n in (0..50).filter(|&n| n != 1) {
+            let d = derangement(&mut rng, n);
+            let mut seen = alloc::vec![false; n];
+            for (i, &x) in d.iter().enumerate() {
+                assert!(i != x && !seen[x]);
+                seen[x] = true;
+            }
+        }
+    }
+
+    #[test]
+    fn choose_mut_is_uniform() {
+        let mut rng = Rng::with_seed(42);
+
+        assert_eq!(choose_mut(&mut rng, &mut []), None::<&mut u8>);
+
+        let total = 100_000;
+        let mut values = [0; 4];
+        for _ in 0..total {
+            *choose_mut(&mut rng, &mut values).unwrap() += 1;
+        }
+        assert!(values.iter().all(|&n| (24_000..=26_000).contains(&n)));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn choose_multiple_shuffled_returns_distinct_elements() {
+        let mut rng = Rng::with_seed(42);
+
+        let values: Vec<usize> = (0..20).collect();
+        for n in 0..25 {
+            let chosen = choose_multiple_shuffled(&mut rng, &values, n);
+            assert_eq!(chosen.len(), n.min(20));
+
+            let mut seen = [false; 20];
+            for &&value in chosen.iter() {
+                assert!(!seen[value]);
+                seen[value] = true;
+            }
+        }
+
+        // Method call syntax must reach this implementation rather than an
+        // inherent method of `Rng` with the same name.
+        use crate::RngExt;
+        assert_eq!(
+            Rng::with_seed(42).choose_multiple_shuffled(&values, 3),
+            choose_multiple_shuffled(&mut Rng::with_seed(42), &values, 3)
+        );
+    }
+
+    #[test]
+    fn shuffle_impl_is_uniform() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 100_000;
+        let mut counts = [[0; 10]; 10];
+        for _ in 0..total {
+            let mut values = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9];
+            shuffle_impl(&mut rng, &mut values);
+            for (position, &value) in values.iter().enumerate() {
+                counts[value][position] += 1;
+            }
+        }
+
+        for row in counts.iter() {
+            for &count in row.iter() {
+                assert!((9_500..=10_500).contains(&count), "{:?}", counts);
+            }
+        }
+    }
+
+    #[test]
+    fn partial_shuffle_chooses_uniform_prefix() {
+        let mut rng = Rng::with_seed(42);
+
+        let total = 100_000;
+        let mut counts = [[0; 3]; 8];
+        for _ in 0..total {
+            let mut values = [0, 1, 2, 3, 4, 5, 6, 7];
+            let (chosen, rest) = partial_shuffle(&mut rng, &mut values, 3);
+            assert_eq!((chosen.len(), rest.len()), (3, 5));
+            for (position, &value) in chosen.iter().enumerate() {
+                counts[value][position] += 1;
+            }
+
+            values.sort_unstable();
+            assert_eq!(values, [0, 1, 2, 3, 4, 5, 6, 7]);
+        }
+
+        // Every element is at every position of the prefix with probability
+        // 1/8.
+        for row in counts.iter() {
+            for &count in row.iter() {
+                assert!((12_000..=13_000).contains(&count), "{:?}", counts);
+            }
+        }
+
+        let mut values = [1, 2];
+        let (chosen, rest) = partial_shuffle(&mut rng, &mut values, 5);
+        assert_eq!((chosen.len(), rest.len()), (2, 0));
+    }
+
+    #[test]
+    fn shuffle_blocks_preserves_blocks() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut moved = false;
+        for _ in 0..100 {
+            let mut values = [0; 23];
+            for (i, x) in values.iter_mut().enumerate() {
+                *x = i;
+            }
+            shuffle_blocks(&mut rng, &mut values, 5);
+
+            // Each full block is still a contiguous increasing run starting at
+            // a multiple of the block size.
+            for (i, block) in values[..20].chunks(5).enumerate() {
+                assert_eq!(block[0] % 5, 0);
+                assert!(block.windows(2).all(|w| w[1] == w[0] + 1));
+                moved |= block[0] != i * 5;
+            }
+            assert_eq!(values[20..], [20, 21, 22]);
+        }
+        assert!(moved);
+    }
+
+    #[test]
+    #[should_panic(expected = "block size must not be zero")]
+    fn shuffle_blocks_rejects_zero_block_size() {
+        shuffle_blocks(&mut Rng::with_seed(42), &mut [1, 2, 3], 0);
+    }
+}
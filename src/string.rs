@@ -0,0 +1,227 @@
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::{fill, BaseRng};
+
+const BASE64_URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const ALPHANUMERIC: &[u8; 62] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+
+pub(super) fn string_from_chars(rng: &mut impl BaseRng, len: usize, charset: &[char]) -> String {
+    assert!(!charset.is_empty(), "charset must not be empty");
+
+    (0..len)
+        .map(|_| charset[rng.usize(..charset.len())])
+        .collect()
+}
+
+pub(super) fn alphanumeric_string(rng: &mut impl BaseRng, len: usize) -> String {
+    let mut s = String::with_capacity(len);
+    push_alphanumeric(rng, &mut s, len);
+    s
+}
+
+pub(super) fn push_alphanumeric(rng: &mut impl BaseRng, out: &mut String, len: usize) {
+    out.reserve(len);
+    for _ in 0..len {
+        out.push(ALPHANUMERIC[rng.usize(..ALPHANUMERIC.len())] as char);
+    }
+}
+
+pub(super) fn valid_utf8(rng: &mut impl BaseRng, num_chars: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(num_chars * 2);
+    let mut buf = [0; 4];
+    for _ in 0..num_chars {
+        // Choosing the encoded length first makes multibyte sequences common.
+        // A uniform scalar value would almost always take 4 bytes.
+        let scalar = match rng.usize(..4) {
+            0 => rng.usize(..0x80),
+            1 => rng.usize(0x80..0x800),
+            // Skip the surrogates in 0xD800..0xE000.
+            2 => {
+                let x = rng.usize(0x800..0x10000 - 0x800);
+                if x >= 0xD800 {
+                    x + 0x800
+                } else {
+                    x
+                }
+            }
+            _ => rng.usize(0x10000..0x110000),
+        };
+        let c = core::char::from_u32(scalar as u32).unwrap();
+        bytes.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+    }
+
+    bytes
+}
+
+pub(super) fn base64_string(rng: &mut impl BaseRng, num_bytes: usize) -> String {
+    let mut bytes = vec![0; num_bytes];
+    fill::fill_bytes(rng, &mut bytes);
+
+    let mut s = String::with_capacity((num_bytes + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        // Pack up to 3 bytes into the high bits of a 24-bit group and emit one
+        // character for every started 6 bits.
+        let mut group = 0u32;
+        for (i, &byte) in chunk.iter().enumerate() {
+            group |= (byte as u32) << (16 - 8 * i);
+        }
+        let chars = (chunk.len() * 8 + 5) / 6;
+        for i in 0..4 {
+            if i < chars {
+                let index = (group >> (18 - 6 * i)) & 0x3f;
+                s.push(BASE64_URL_ALPHABET[index as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+
+    s
+}
+
+pub(super) fn base32_string(rng: &mut impl BaseRng, num_bytes: usize) -> String {
+    let mut bytes = vec![0; num_bytes];
+    fill::fill_bytes(rng, &mut bytes);
+
+    let mut s = String::with_capacity((num_bytes + 4) / 5 * 8);
+    for chunk in bytes.chunks(5) {
+        // Pack up to 5 bytes into the high bits of a 40-bit group and emit one
+        // character for every started 5 bits.
+        let mut group = 0u64;
+        for (i, &byte) in chunk.iter().enumerate() {
+            group |= (byte as u64) << (32 - 8 * i);
+        }
+        let chars = (chunk.len() * 8 + 4) / 5;
+        for i in 0..8 {
+            if i < chars {
+                let index = (group >> (35 - 5 * i)) & 0x1f;
+                s.push(BASE32_ALPHABET[index as usize] as char);
+            } else {
+                s.push('=');
+            }
+        }
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn alphanumeric_string_has_len_and_charset() {
+        let mut rng = Rng::with_seed(42);
+
+        for &len in [0, 1, 7, 100].iter() {
+            let s = alphanumeric_string(&mut rng, len);
+            assert_eq!(s.len(), len);
+            assert!(s.chars().all(|c| c.is_ascii_alphanumeric()));
+        }
+
+        let mut s = String::from("id-");
+        push_alphanumeric(&mut rng, &mut s, 10);
+        push_alphanumeric(&mut rng, &mut s, 0);
+        assert_eq!(s.len(), 13);
+        assert!(s.starts_with("id-"));
+        assert!(s[3..].chars().all(|c| c.is_ascii_alphanumeric()));
+
+        // All 62 characters show up.
+        let s = alphanumeric_string(&mut rng, 10_000);
+        let mut seen = [false; 128];
+        for c in s.chars() {
+            seen[c as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&seen| seen).count(), 62);
+    }
+
+    #[test]
+    fn string_from_chars_uses_charset() {
+        let mut rng = Rng::with_seed(42);
+
+        let charset = ['a', 'é', '日', '🦀'];
+        for len in 0..50 {
+            let s = string_from_chars(&mut rng, len, &charset);
+            assert_eq!(s.chars().count(), len);
+            assert!(s.chars().all(|c| charset.contains(&c)));
+        }
+
+        let s = string_from_chars(&mut rng, 100_000, &['0', '1']);
+        let zeros = s.chars().filter(|&c| c == '0').count();
+        assert!(
+            (49_000..=51_000).contains(&zeros),
+            "'0' sampled {} times, expected ~50000",
+            zeros
+        );
+    }
+
+    #[test]
+    fn valid_utf8_decodes_to_num_chars() {
+        let mut rng = Rng::with_seed(42);
+
+        let mut lengths = [0; 5];
+        for num_chars in 0..100 {
+            let bytes = valid_utf8(&mut rng, num_chars);
+            let s = core::str::from_utf8(&bytes).unwrap();
+            assert_eq!(s.chars().count(), num_chars);
+            for c in s.chars() {
+                lengths[c.len_utf8()] += 1;
+            }
+        }
+        assert!(lengths[1..].iter().all(|&n| n > 1000));
+    }
+
+    // Decode `s` with the given alphabet, returning `None` on an invalid
+    // character or malformed padding.
+    fn decode(s: &str, alphabet: &[u8], bits_per_char: usize) -> Option<usize> {
+        let group_chars = if bits_per_char == 6 { 4 } else { 8 };
+        if s.len() % group_chars != 0 {
+            return None;
+        }
+
+        let data = s.trim_end_matches('=');
+        if !data.bytes().all(|c| alphabet.contains(&c)) {
+            return None;
+        }
+        let padding = s.len() - data.len();
+        if padding >= group_chars {
+            return None;
+        }
+
+        Some(data.len() * bits_per_char / 8)
+    }
+
+    #[test]
+    fn base64_and_base32_strings() {
+        let mut rng = Rng::with_seed(42);
+
+        for num_bytes in 0..50 {
+            let s = base64_string(&mut rng, num_bytes);
+            assert_eq!(s.len(), (num_bytes + 2) / 3 * 4);
+            assert_eq!(decode(&s, BASE64_URL_ALPHABET, 6), Some(num_bytes));
+
+            let s = base32_string(&mut rng, num_bytes);
+            assert_eq!(s.len(), (num_bytes + 4) / 5 * 8);
+            assert_eq!(decode(&s, BASE32_ALPHABET, 5), Some(num_bytes));
+        }
+
+        // Decoding a full base32 group recovers the generated bytes.
+        let mut rng = Rng::with_seed(7);
+        let mut bytes = [0; 5];
+        fill::fill_bytes(&mut rng, &mut bytes);
+        let mut rng = Rng::with_seed(7);
+        let s = base32_string(&mut rng, 5);
+        let mut group = 0u64;
+        for c in s.bytes() {
+            let index = BASE32_ALPHABET.iter().position(|&a| a == c).unwrap();
+            group = group << 5 | index as u64;
+        }
+        assert_eq!(group.to_be_bytes()[3..], bytes);
+    }
+}
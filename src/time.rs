@@ -0,0 +1,135 @@
+use core::ops::{Bound, RangeBounds};
+use core::time::Duration;
+
+use crate::BaseRng;
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+pub(super) fn duration_range(
+    rng: &mut impl BaseRng,
+    range: impl RangeBounds<Duration>,
+) -> Duration {
+    // `Duration::MAX` was stabilized in Rust 1.53. Our current MSRV is 1.43.
+    let max_nanos = Duration::new(u64::MAX, 999_999_999).as_nanos();
+
+    let low = match range.start_bound() {
+        Bound::Included(d) => d.as_nanos(),
+        Bound::Excluded(d) => d.as_nanos() + 1,
+        Bound::Unbounded => 0,
+    };
+    let high = match range.end_bound() {
+        Bound::Included(d) => d.as_nanos(),
+        Bound::Excluded(d) => d
+            .as_nanos()
+            .checked_sub(1)
+            .unwrap_or_else(|| panic!("empty range")),
+        Bound::Unbounded => max_nanos,
+    };
+    assert!(low <= high && low <= max_nanos, "empty range");
+
+    // The number of nanoseconds in a `Duration` fits in 94 bits, so the span
+    // can't overflow. Draws below `2^128 mod span` are rejected to avoid the
+    // modulo bias.
+    let span = high - low + 1;
+    let threshold = span.wrapping_neg() % span;
+    let nanos = loop {
+        let x = rng.u128();
+        if x >= threshold {
+            break low + x % span;
+        }
+    };
+
+    Duration::new(
+        (nanos / NANOS_PER_SEC) as u64,
+        (nanos % NANOS_PER_SEC) as u32,
+    )
+}
+
+pub(super) fn backoff_jitter(
+    rng: &mut impl BaseRng,
+    base: Duration,
+    cap: Duration,
+    attempt: u32,
+) -> Duration {
+    // Full jitter: https://aws.amazon.com/blogs/architecture/exponential-backoff-and-jitter/
+    // Overflowing the exponential delay means it is above the cap anyway.
+    let delay = 1u32
+        .checked_shl(attempt)
+        .and_then(|factor| base.checked_mul(factor))
+        .map_or(cap, |delay| delay.min(cap));
+
+    duration_range(rng, ..=delay)
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn duration_range_in_bounds() {
+        let mut rng = Rng::with_seed(42);
+
+        let low = Duration::from_millis(1500);
+        let high = Duration::from_secs(3);
+        for _ in 0..10000 {
+            let d = duration_range(&mut rng, low..high);
+            assert!(d >= low && d < high);
+            let d = duration_range(&mut rng, ..=high);
+            assert!(d <= high);
+        }
+
+        // Both ends of a tiny inclusive range are sampled.
+        let (a, b) = (Duration::from_nanos(7), Duration::from_nanos(8));
+        let mut seen = [false; 2];
+        for _ in 0..100 {
+            let d = duration_range(&mut rng, a..=b);
+            assert!(d == a || d == b);
+            seen[(d == b) as usize] = true;
+        }
+        assert_eq!(seen, [true, true]);
+
+        let max = Duration::new(u64::MAX, 999_999_999);
+        assert_eq!(duration_range(&mut rng, max..=max), max);
+        assert!(
+            duration_range(&mut rng, max - Duration::from_secs(1)..)
+                >= max - Duration::from_secs(1)
+        );
+        let _ = duration_range(&mut rng, ..);
+    }
+
+    #[test]
+    fn backoff_jitter_grows_up_to_cap() {
+        let mut rng = Rng::with_seed(42);
+
+        let base = Duration::from_millis(100);
+        let cap = Duration::from_secs(10);
+        let mut max = Duration::from_secs(0);
+        for _ in 0..1000 {
+            let d = backoff_jitter(&mut rng, base, cap, 0);
+            assert!(d <= base);
+            max = max.max(d);
+        }
+        assert!(max > base * 9 / 10);
+
+        let mut sum = Duration::from_secs(0);
+        for &attempt in [10, 31, 32, 1000].iter() {
+            for _ in 0..1000 {
+                let d = backoff_jitter(&mut rng, base, cap, attempt);
+                assert!(d <= cap);
+                sum += d;
+            }
+        }
+        // Saturated delays are uniform in `[0, cap]`.
+        let mean = sum.as_secs_f64() / 4000.0;
+        assert!((mean - 5.0).abs() < 0.2, "mean is {}s", mean);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn duration_range_rejects_empty() {
+        let d = Duration::from_secs(1);
+        duration_range(&mut Rng::with_seed(42), d..d);
+    }
+}
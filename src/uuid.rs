@@ -0,0 +1,79 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{fill, BaseRng};
+
+pub(super) fn uuid_v4(rng: &mut impl BaseRng) -> [u8; 16] {
+    let mut bytes = [0; 16];
+    fill::fill_bytes(rng, &mut bytes);
+
+    // The high nibble of byte 6 is the version, the two high bits of byte 8
+    // are the RFC 4122 variant `10`.
+    bytes[6] = (bytes[6] & 0x0f) | 0x40;
+    bytes[8] = (bytes[8] & 0x3f) | 0x80;
+    bytes
+}
+
+#[cfg(feature = "alloc")]
+pub(super) fn uuid_v4_string(rng: &mut impl BaseRng) -> String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+    let bytes = uuid_v4(rng);
+    let mut s = String::with_capacity(36);
+    for (i, &byte) in bytes.iter().enumerate() {
+        // Hyphens separate the groups of 4, 2, 2, 2 and 6 bytes.
+        if matches!(i, 4 | 6 | 8 | 10) {
+            s.push('-');
+        }
+        s.push(HEX_DIGITS[(byte >> 4) as usize] as char);
+        s.push(HEX_DIGITS[(byte & 0x0f) as usize] as char);
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    fn uuid_v4_has_version_and_variant() {
+        let mut rng = Rng::with_seed(42);
+
+        for _ in 0..1000 {
+            let bytes = uuid_v4(&mut rng);
+            assert_eq!(bytes[6] >> 4, 4);
+            assert_eq!(bytes[8] >> 6, 0b10);
+        }
+
+        assert_ne!(uuid_v4(&mut rng), uuid_v4(&mut rng));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn uuid_v4_string_is_hyphenated_hex() {
+        let mut rng = Rng::with_seed(42);
+        let mut other = Rng::with_seed(42);
+
+        for _ in 0..100 {
+            let s = uuid_v4_string(&mut rng);
+            let bytes = uuid_v4(&mut other);
+            assert_eq!(s.len(), 36);
+            for (i, c) in s.chars().enumerate() {
+                if matches!(i, 8 | 13 | 18 | 23) {
+                    assert_eq!(c, '-');
+                } else {
+                    assert!(c.is_ascii_hexdigit() && !c.is_ascii_uppercase());
+                }
+            }
+
+            let hex: String = s.chars().filter(|&c| c != '-').collect();
+            for (i, &byte) in bytes.iter().enumerate() {
+                assert_eq!(u8::from_str_radix(&hex[2 * i..2 * i + 2], 16), Ok(byte));
+            }
+            assert_eq!(&s[14..15], "4");
+        }
+    }
+}
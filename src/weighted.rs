@@ -0,0 +1,338 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use crate::float_normal::FloatMathExt;
+use crate::BaseRng;
+#[cfg(feature = "std")]
+use crate::GlobalRng;
+#[cfg(feature = "alloc")]
+use crate::Rng;
+
+/// A table for repeatedly choosing random indices with probability
+/// proportional to fixed weights in `O(1)` time.
+///
+/// Building the table takes `O(n)` time using Vose's alias method.
+///
+/// ```
+/// use fastrand::Rng;
+/// use fastrand_contrib::AliasTable;
+///
+/// let table = AliasTable::new(&[1.0, 0.0, 3.0]);
+///
+/// let mut rng = Rng::with_seed(0x1234);
+/// let i = table.sample(&mut rng);
+/// assert!(i == 0 || i == 2);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone)]
+pub struct AliasTable {
+    /// The probability of keeping the index drawn uniformly at random.
+    prob: Vec<f64>,
+    /// The index to use instead if the drawn index is not kept.
+    alias: Vec<usize>,
+}
+
+#[cfg(feature = "alloc")]
+impl AliasTable {
+    /// Create a new table from the given weights.
+    ///
+    /// The weights don't need to be normalized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no weights, if any weight is negative or not
+    /// finite, or if all weights are zero.
+    pub fn new(weights: &[f64]) -> Self {
+        assert!(!weights.is_empty(), "weights must not be empty");
+
+        let mut total = 0.0;
+        for &weight in weights.iter() {
+            assert!(
+                weight >= 0.0 && weight.is_finite(),
+                "weights must be non-negative and finite, but got {}",
+                weight
+            );
+            total += weight;
+        }
+        assert!(total > 0.0, "weights must not all be zero");
+
+        // Scale the weights so that their mean is 1, then repeatedly fill up
+        // the column of an index with less than 1 by an index with more.
+        let n = weights.len();
+        let mut prob: Vec<f64> = weights.iter().map(|&w| w * n as f64 / total).collect();
+        let mut alias: Vec<usize> = (0..n).collect();
+        let (mut small, mut large): (Vec<usize>, Vec<usize>) = (0..n).partition(|&i| prob[i] < 1.0);
+        while let (Some(&s), Some(&l)) = (small.last(), large.last()) {
+            small.pop();
+            alias[s] = l;
+            prob[l] -= 1.0 - prob[s];
+            if prob[l] < 1.0 {
+                large.pop();
+                small.push(l);
+            }
+        }
+
+        // Whatever is left over is 1 up to rounding errors.
+        for i in small.into_iter().chain(large) {
+            prob[i] = 1.0;
+        }
+
+        AliasTable { prob, alias }
+    }
+
+    /// Choose a random index.
+    pub fn sample(&self, rng: &mut Rng) -> usize {
+        self.sample_impl(rng)
+    }
+
+    /// Choose a random index using the global thread-local RNG.
+    #[cfg(feature = "std")]
+    pub fn sample_global(&self) -> usize {
+        self.sample_impl(&mut GlobalRng)
+    }
+
+    fn sample_impl(&self, rng: &mut impl BaseRng) -> usize {
+        let i = rng.usize(..self.prob.len());
+        if rng.f64() < self.prob[i] {
+            i
+        } else {
+            self.alias[i]
+        }
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub(super) fn weighted_index_log(rng: &mut impl BaseRng, log_weights: &[f64]) -> usize {
+    assert!(!log_weights.is_empty(), "log weights must not be empty");
+
+    // Log-sum-exp trick: shifting all log weights by the maximum keeps the
+    // largest exponentiated weight at 1, so nothing overflows and at least one
+    // weight doesn't underflow.
+    let max = log_weights
+        .iter()
+        .fold(f64::NEG_INFINITY, |max, &w| if w > max { w } else { max });
+    assert!(
+        max.is_finite(),
+        "log weights must contain a finite maximum, but it is {}",
+        max
+    );
+
+    // Exponentiate once up front, since the weights are walked twice.
+    let weights: Vec<f64> = log_weights
+        .iter()
+        .map(|&w| FloatMathExt::exp(w - max))
+        .collect();
+    weighted_index(rng, &weights)
+}
+
+pub(super) fn weighted_index(rng: &mut impl BaseRng, weights: &[f64]) -> usize {
+    weighted_index_impl(rng, weights.iter().copied())
+}
+
+pub(super) fn weighted_index_with_default(
+    rng: &mut impl BaseRng,
+    weights: &[Option<f64>],
+    default: f64,
+) -> usize {
+    weighted_index_impl(rng, weights.iter().map(|w| w.unwrap_or(default)))
+}
+
+pub(super) fn weighted_index_from_cumulative(rng: &mut impl BaseRng, cumulative: &[f64]) -> usize {
+    let total = *cumulative
+        .last()
+        .expect("cumulative weights must not be empty");
+    assert!(
+        total > 0.0 && total.is_finite(),
+        "total weight must be positive and finite, but got {}",
+        total
+    );
+    debug_assert!(
+        cumulative[0] >= 0.0 && cumulative.windows(2).all(|w| w[0] <= w[1]),
+        "cumulative weights must be non-negative and non-decreasing"
+    );
+
+    // Find the first index whose cumulative weight exceeds the target. Its
+    // weight is positive, since the previous cumulative weight is at most the
+    // target.
+    let target = rng.f64() * total;
+    let index = first_index_where(cumulative, |c| c > target);
+    if index < cumulative.len() {
+        return index;
+    }
+
+    // Rounding can make the target equal to the total; attribute it to the
+    // last index with a non-zero weight.
+    first_index_where(cumulative, |c| c >= total)
+}
+
+/// Binary search for the first index where `pred` holds, assuming that it
+/// holds for all later indices as well.
+fn first_index_where(slice: &[f64], pred: impl Fn(f64) -> bool) -> usize {
+    let (mut lo, mut hi) = (0, slice.len());
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(slice[mid]) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    lo
+}
+
+pub(super) fn weighted_index_impl(
+    rng: &mut impl BaseRng,
+    weights: impl Iterator<Item = f64> + Clone,
+) -> usize {
+    let mut total = 0.0;
+    let mut last_positive = None;
+    for (i, weight) in weights.clone().enumerate() {
+        assert!(
+            weight >= 0.0 && weight.is_finite(),
+            "weights must be non-negative and finite, but got {}",
+            weight
+        );
+        if weight > 0.0 {
+            last_positive = Some(i);
+        }
+        total += weight;
+    }
+    let last_positive = last_positive.expect("at least one weight must be positive");
+
+    let mut target = rng.f64() * total;
+    for (i, weight) in weights.enumerate() {
+        if target < weight {
+            return i;
+        }
+        target -= weight;
+    }
+
+    // Rounding errors can leave a small remainder; attribute it to the last
+    // index with a non-zero weight.
+    last_positive
+}
+
+#[cfg(test)]
+mod tests {
+    use fastrand::Rng;
+
+    use super::*;
+
+    #[test]
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn weighted_index_log_follows_weights() {
+        let mut rng = Rng::with_seed(42);
+
+        let log_weights = [FloatMathExt::ln(1.0), FloatMathExt::ln(3.0)];
+        let total = 10000;
+        let ones = (0..total)
+            .filter(|_| weighted_index_log(&mut rng, &log_weights) == 1)
+            .count();
+
+        let ones = ones as f64 / total as f64 * 100.0;
+        assert!(
+            (73.0..=77.0).contains(&ones),
+            "index 1 should be sampled ~75%, but is {}%",
+            ones
+        );
+    }
+
+    #[test]
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    fn weighted_index_log_handles_extreme_weights() {
+        let mut rng = Rng::with_seed(42);
+
+        let log_weights = [-2000.0, -2000.0 + FloatMathExt::ln(3.0)];
+        for _ in 0..100 {
+            assert!(weighted_index_log(&mut rng, &log_weights) < 2);
+        }
+    }
+
+    #[test]
+    fn weighted_index_follows_weights() {
+        let mut rng = Rng::with_seed(42);
+
+        let weights = [1.0, 8.0, 1.0, 0.0];
+        let total = 100_000;
+        let mut counts = [0; 4];
+        for _ in 0..total {
+            counts[weighted_index(&mut rng, &weights)] += 1;
+        }
+
+        assert!((9_500..=10_500).contains(&counts[0]));
+        assert!((79_000..=81_000).contains(&counts[1]));
+        assert!((9_500..=10_500).contains(&counts[2]));
+        assert_eq!(counts[3], 0);
+    }
+
+    #[test]
+    fn weighted_index_with_default_substitutes_missing_weights() {
+        let mut rng = Rng::with_seed(42);
+
+        let weights = [Some(1.0), None, Some(0.0), None];
+        let total = 10000;
+        let mut counts = [0; 4];
+        for _ in 0..total {
+            counts[weighted_index_with_default(&mut rng, &weights, 2.0)] += 1;
+        }
+
+        // Effective weights are [1, 2, 0, 2].
+        assert!((1800..=2200).contains(&counts[0]));
+        assert!((3800..=4200).contains(&counts[1]));
+        assert_eq!(counts[2], 0);
+        assert!((3800..=4200).contains(&counts[3]));
+    }
+
+    #[test]
+    fn weighted_index_from_cumulative_matches_raw_weights() {
+        let weights = [Some(1.0), Some(0.0), Some(3.0), Some(2.0), Some(0.0)];
+        let cumulative = [1.0, 1.0, 4.0, 6.0, 6.0];
+
+        let mut rng1 = Rng::with_seed(42);
+        let mut rng2 = Rng::with_seed(42);
+        for _ in 0..10000 {
+            assert_eq!(
+                weighted_index_from_cumulative(&mut rng1, &cumulative),
+                weighted_index_with_default(&mut rng2, &weights, 0.0)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "total weight must be positive")]
+    fn weighted_index_from_cumulative_rejects_zero_total() {
+        weighted_index_from_cumulative(&mut Rng::with_seed(42), &[0.0, 0.0]);
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn alias_table_follows_weights() {
+        let mut rng = Rng::with_seed(42);
+
+        let weights = [1.0, 0.0, 5.0, 2.0, 0.5, 1.5];
+        let table = AliasTable::new(&weights);
+
+        let total = 100_000;
+        let mut counts = [0; 6];
+        for _ in 0..total {
+            counts[table.sample(&mut rng)] += 1;
+        }
+
+        let total_weight: f64 = weights.iter().sum();
+        for (&count, &weight) in counts.iter().zip(weights.iter()) {
+            let freq = count as f64 / total as f64;
+            let expected = weight / total_weight;
+            assert!(
+                (freq - expected).abs() < 0.01,
+                "index should be sampled ~{}, but is {}",
+                expected,
+                freq
+            );
+        }
+        assert_eq!(counts[1], 0);
+
+        let single = AliasTable::new(&[3.0]);
+        assert_eq!(single.sample(&mut rng), 0);
+    }
+}